@@ -50,7 +50,9 @@ pub(crate) async fn messages_find<B: StorageBackend>(
 ) -> Result<impl Reply, Rejection> {
     let index_bytes = hex::decode(index.clone())
         .map_err(|_| reject::custom(CustomRejection::BadRequest("Invalid index".to_owned())))?;
-    let hashed_index = IndexationPayload::new(&index_bytes, &[]).unwrap().hash();
+    let hashed_index = IndexationPayload::new(&index_bytes, &[])
+        .map_err(|_| reject::custom(CustomRejection::BadRequest("Invalid index".to_owned())))?
+        .hash();
 
     let mut fetched = match Fetch::<HashedIndex, Vec<MessageId>>::fetch(storage.deref(), &hashed_index)
         .await
@@ -63,6 +65,7 @@ pub(crate) async fn messages_find<B: StorageBackend>(
         None => vec![],
     };
 
+    // There is no pagination: `count` is the number of matching ids and at most `max_results` of them are returned.
     let count = fetched.len();
     let max_results = 1000;
     fetched.truncate(max_results);
@@ -10,7 +10,10 @@ use crypto::{
     signatures::ed25519::{PublicKey, Signature},
 };
 
-use core::{convert::TryInto, str::FromStr};
+use core::{
+    convert::{TryFrom, TryInto},
+    str::FromStr,
+};
 
 pub const ED25519_ADDRESS_LENGTH: usize = 32;
 
@@ -64,6 +67,18 @@ impl From<[u8; ED25519_ADDRESS_LENGTH]> for Ed25519Address {
     }
 }
 
+impl TryFrom<&[u8]> for Ed25519Address {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; ED25519_ADDRESS_LENGTH] = bytes
+            .try_into()
+            .map_err(|_| Error::InvalidAddressLength(ED25519_ADDRESS_LENGTH, bytes.len()))?;
+
+        Ok(Ed25519Address::from(bytes))
+    }
+}
+
 impl FromStr for Ed25519Address {
     type Err = Error;
 
@@ -14,6 +14,9 @@ use bech32::{self, FromBase32, ToBase32, Variant};
 use alloc::{str::FromStr, string::String};
 use core::convert::TryFrom;
 
+// BIP-173 limit, not enforced by `bech32::decode`.
+const BECH32_LENGTH_MAX: usize = 90;
+
 #[non_exhaustive]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 #[cfg_attr(
@@ -32,11 +35,55 @@ impl Address {
         }
     }
 
+    /// Decodes an address from either a Bech32 or a Bech32m string, the checksum variant is not checked.
+    /// Use `try_from_bech32_strict` or `try_from_bech32m` to only accept one of them.
     pub fn try_from_bech32(addr: &str) -> Result<Self, Error> {
+        Self::decode_bech32(addr).map(|(_, address, _)| address)
+    }
+
+    /// Decodes an address from a Bech32 string, rejecting Bech32m strings.
+    pub fn try_from_bech32_strict(addr: &str) -> Result<Self, Error> {
+        match Self::decode_bech32(addr)? {
+            (_, address, Variant::Bech32) => Ok(address),
+            _ => Err(Error::InvalidAddress),
+        }
+    }
+
+    /// Decodes an address from a Bech32m string, rejecting Bech32 strings.
+    /// Bech32 and Bech32m (BIP-350) only differ in the constant used by the checksum, Bech32m fixing a weakness of
+    /// Bech32 regarding insertions and deletions of `q` characters before a trailing `p`.
+    pub fn try_from_bech32m(addr: &str) -> Result<Self, Error> {
+        match Self::decode_bech32(addr)? {
+            (_, address, Variant::Bech32m) => Ok(address),
+            _ => Err(Error::InvalidAddress),
+        }
+    }
+
+    /// Decodes an address from a Bech32 or Bech32m string whose human-readable part is `expected_hrp`, returning
+    /// `Error::InvalidAddress` if the string does not decode or its human-readable part differs.
+    pub fn try_from_bech32_with_hrp(addr: &str, expected_hrp: &str) -> Result<Self, Error> {
+        match Self::decode_bech32(addr)? {
+            (hrp, address, _) if hrp == expected_hrp => Ok(address),
+            _ => Err(Error::InvalidAddress),
+        }
+    }
+
+    fn decode_bech32(addr: &str) -> Result<(String, Self, Variant), Error> {
+        if addr.len() > BECH32_LENGTH_MAX {
+            return Err(Error::InvalidAddress);
+        }
+
         match bech32::decode(addr) {
-            Ok((_hrp, data, _)) => {
+            Ok((hrp, data, variant)) => {
                 let bytes = Vec::<u8>::from_base32(&data).map_err(|_| Error::InvalidAddress)?;
-                Self::unpack(&mut bytes.as_slice()).map_err(|_| Error::InvalidAddress)
+                let address = Self::unpack(&mut bytes.as_slice()).map_err(|_| Error::InvalidAddress)?;
+
+                // The decoded data must be exactly one address, trailing bytes are not tolerated.
+                if address.packed_len() != bytes.len() {
+                    return Err(Error::InvalidAddress);
+                }
+
+                Ok((hrp, address, variant))
             }
             Err(_) => Err(Error::InvalidAddress),
         }
@@ -46,6 +93,12 @@ impl Address {
         bech32::encode(hrp, self.pack_new().to_base32(), Variant::Bech32).expect("Invalid address.")
     }
 
+    /// Encodes the address as a Bech32m string, which unlike Bech32 detects insertions and deletions of `q`
+    /// characters before a trailing `p`.
+    pub fn to_bech32m(&self, hrp: &str) -> String {
+        bech32::encode(hrp, self.pack_new().to_base32(), Variant::Bech32m).expect("Invalid address.")
+    }
+
     pub fn verify(&self, msg: &[u8], signature: &SignatureUnlock) -> Result<(), Error> {
         match self {
             Address::Ed25519(address) => {
@@ -4,6 +4,14 @@
 use core::ops::{Range, RangeInclusive};
 
 pub const IOTA_SUPPLY: u64 = 2_779_530_283_277_761;
+
+/// An output is considered dust if its amount is strictly less than this value.
+pub const DUST_THRESHOLD: u64 = 1_000_000;
+/// Divisor used to compute the allowed dust outputs on an address.
+pub const DUST_ALLOWANCE_DIVISOR: u64 = 100_000;
+/// Maximum number of dust outputs for an address.
+pub const DUST_OUTPUTS_MAX: u64 = 100;
+
 // TODO split
 pub const INPUT_OUTPUT_COUNT_MAX: usize = 127;
 pub const INPUT_OUTPUT_COUNT_RANGE: RangeInclusive<usize> = 1..=INPUT_OUTPUT_COUNT_MAX;
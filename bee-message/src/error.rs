@@ -1,6 +1,8 @@
 // Copyright 2020 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::output::OutputId;
+
 use core::fmt;
 
 #[derive(Debug)]
@@ -26,6 +28,7 @@ pub enum Error {
     InvalidParentsCount(usize),
     DuplicateError,
     InvalidAddress,
+    InvalidAddressLength(usize, usize),
     MissingField(&'static str),
     InvalidPayloadLength(usize, usize),
     MissingPayload,
@@ -38,17 +41,21 @@ pub enum Error {
     MilestonePublicKeysNotUniqueSorted,
     MilestoneInvalidPublicKeyCount(usize),
     MilestoneInvalidSignatureCount(usize),
+    MilestoneInvalidSignatureLength(usize),
     MilestonePublicKeysSignaturesCountMismatch(usize, usize),
     InvalidUnlockBlockReference(usize),
     DuplicateSignature(usize),
-    TransactionInputsNotSorted,
-    TransactionOutputsNotSorted,
-    MigratedFundsNotSorted,
+    TransactionInputsNotSorted(usize),
+    TransactionOutputsNotSorted(usize),
+    MigratedFundsNotSorted(usize),
     RemainingBytesAfterMessage,
     ParentsNotUniqueSorted,
     TailTransactionHashNotUnique(usize, usize),
     SignaturePublicKeyMismatch(String, String),
     InvalidSignature,
+    InsufficientPowScore(f64, f64),
+    ConflictingInputs(OutputId, usize, usize),
+    DustAllowanceExceeded(usize, usize),
 }
 
 impl std::error::Error for Error {}
@@ -87,6 +94,9 @@ impl fmt::Display for Error {
             }
             Error::DuplicateError => write!(f, "The object in the set must be unique."),
             Error::InvalidAddress => write!(f, "Invalid address provided."),
+            Error::InvalidAddressLength(expected, actual) => {
+                write!(f, "Invalid address length: expected {} got {}.", expected, actual)
+            }
             Error::MissingField(s) => write!(f, "Missing required field: {}.", s),
             Error::InvalidPayloadLength(expected, actual) => {
                 write!(f, "Invalid payload length: expected {}, got {}.", expected, actual)
@@ -113,6 +123,9 @@ impl fmt::Display for Error {
             Error::MilestoneInvalidSignatureCount(count) => {
                 write!(f, "Invalid milestone signature count: {}.", count)
             }
+            Error::MilestoneInvalidSignatureLength(length) => {
+                write!(f, "Invalid milestone signature length: {}.", length)
+            }
             Error::MilestonePublicKeysSignaturesCountMismatch(kcount, scount) => {
                 write!(
                     f,
@@ -126,14 +139,14 @@ impl fmt::Display for Error {
             Error::DuplicateSignature(index) => {
                 write!(f, "Duplicate signature at index: {0}", index)
             }
-            Error::TransactionInputsNotSorted => {
-                write!(f, "Transaction inputs are not sorted.")
+            Error::TransactionInputsNotSorted(index) => {
+                write!(f, "Transaction inputs are not sorted at index: {0}.", index)
             }
-            Error::TransactionOutputsNotSorted => {
-                write!(f, "Transaction outputs are not sorted.")
+            Error::TransactionOutputsNotSorted(index) => {
+                write!(f, "Transaction outputs are not sorted at index: {0}.", index)
             }
-            Error::MigratedFundsNotSorted => {
-                write!(f, "Migrated funds are not sorted.")
+            Error::MigratedFundsNotSorted(index) => {
+                write!(f, "Migrated funds are not sorted at index: {0}.", index)
             }
             Error::RemainingBytesAfterMessage => {
                 write!(f, "Remaining bytes after message.")
@@ -156,6 +169,27 @@ impl fmt::Display for Error {
                 )
             }
             Error::InvalidSignature => write!(f, "Invalid signature provided."),
+            Error::InsufficientPowScore(score, target) => {
+                write!(
+                    f,
+                    "Insufficient PoW score: expected at least {}, got {}.",
+                    target, score
+                )
+            }
+            Error::ConflictingInputs(output_id, previous, current) => {
+                write!(
+                    f,
+                    "Output {} consumed by both transactions {} and {}.",
+                    output_id, previous, current
+                )
+            }
+            Error::DustAllowanceExceeded(count, max) => {
+                write!(
+                    f,
+                    "Dust allowance exceeded: {} dust outputs for {} allowed.",
+                    count, max
+                )
+            }
         }
     }
 }
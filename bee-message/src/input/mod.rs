@@ -11,6 +11,8 @@ use crate::Error;
 
 use bee_common::packable::{Packable, Read, Write};
 
+use alloc::vec::Vec;
+
 #[non_exhaustive]
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 #[cfg_attr(
@@ -32,6 +34,11 @@ impl Input {
     }
 }
 
+/// Sorts inputs lexicographically by their packed bytes, which is the order a transaction essence expects.
+pub fn canonical_sort_inputs(inputs: &mut Vec<Input>) {
+    inputs.sort_by_cached_key(Packable::pack_new);
+}
+
 impl From<UTXOInput> for Input {
     fn from(input: UTXOInput) -> Self {
         Self::UTXO(input)
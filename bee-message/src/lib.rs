@@ -9,6 +9,7 @@ mod serde;
 mod error;
 mod message;
 mod message_id;
+mod nonce;
 
 pub mod address;
 pub mod constants;
@@ -23,6 +24,7 @@ pub mod solid_entry_point;
 pub mod unlock;
 
 pub use error::Error;
-pub use message::{Message, MessageBuilder, MESSAGE_LENGTH_MAX, MESSAGE_LENGTH_MIN};
+pub use message::{Message, MessageBuilder, StorageEntries, MESSAGE_LENGTH_MAX, MESSAGE_LENGTH_MIN};
 pub use message_id::{MessageId, MESSAGE_ID_LENGTH};
+pub use nonce::Nonce;
 pub use parents::Parents;
@@ -2,12 +2,19 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    payload::{option_payload_pack, option_payload_packed_len, option_payload_unpack, Payload},
-    Error, MessageId, Parents,
+    parents::MESSAGE_PARENTS_RANGE,
+    payload::{
+        indexation::HashedIndex, option_payload_pack, option_payload_packed_len, option_payload_unpack,
+        transaction::Essence, Payload,
+    },
+    Error, MessageId, Nonce, Parents,
 };
 
 use bee_common::packable::{Packable, Read, Write};
-use bee_pow::providers::{Miner, Provider, ProviderBuilder};
+use bee_pow::{
+    providers::{Miner, Provider, ProviderBuilder},
+    score::compute_pow_score,
+};
 
 use crypto::hashes::{blake2b::Blake2b256, Digest};
 
@@ -16,13 +23,35 @@ use std::sync::{atomic::AtomicBool, Arc};
 pub const MESSAGE_LENGTH_MIN: usize = 53;
 pub const MESSAGE_LENGTH_MAX: usize = 32768;
 
+/// The keys and value the storage layer derives from a message.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StorageEntries {
+    message_id: MessageId,
+    bytes: Vec<u8>,
+    hashed_index: Option<HashedIndex>,
+}
+
+impl StorageEntries {
+    pub fn message_id(&self) -> &MessageId {
+        &self.message_id
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn hashed_index(&self) -> Option<&HashedIndex> {
+        self.hashed_index.as_ref()
+    }
+}
+
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Message {
     network_id: u64,
     parents: Parents,
     payload: Option<Payload>,
-    nonce: u64,
+    nonce: Nonce,
 }
 
 impl Message {
@@ -46,35 +75,108 @@ impl Message {
         &self.parents
     }
 
+    /// Returns whether `message_id` is one of the parents of this message.
+    pub fn approves(&self, message_id: &MessageId) -> bool {
+        self.parents.contains(message_id)
+    }
+
     pub fn payload(&self) -> &Option<Payload> {
         &self.payload
     }
 
-    pub fn nonce(&self) -> u64 {
+    pub fn nonce(&self) -> Nonce {
         self.nonce
     }
-}
 
-impl Packable for Message {
-    type Error = Error;
+    /// Returns the message id, the packed message and the hashed index of its indexation payload, if any, including
+    /// one nested in a regular transaction essence.
+    pub fn storage_entries(&self) -> StorageEntries {
+        let (message_id, bytes) = self.id();
+        let indexation = match &self.payload {
+            Some(Payload::Indexation(indexation)) => Some(indexation),
+            Some(Payload::Transaction(transaction)) => {
+                let Essence::Regular(essence) = transaction.essence();
+                match essence.payload() {
+                    Some(Payload::Indexation(indexation)) => Some(indexation),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
 
-    fn packed_len(&self) -> usize {
-        self.network_id.packed_len()
-            + self.parents.packed_len()
-            + option_payload_packed_len(self.payload.as_ref())
-            + self.nonce.packed_len()
+        StorageEntries {
+            message_id,
+            bytes,
+            hashed_index: indexation.map(|indexation| indexation.hash()),
+        }
     }
 
-    fn pack<W: Write>(&self, writer: &mut W) -> Result<(), Self::Error> {
-        self.network_id.pack(writer)?;
-        self.parents.pack(writer)?;
-        option_payload_pack(writer, self.payload.as_ref())?;
-        self.nonce.pack(writer)?;
+    /// Returns a short human-readable description of the message for logging: the start of its id, its number of
+    /// parents and the kind of its payload.
+    pub fn summary(&self) -> String {
+        let payload = match self.payload {
+            None => "None",
+            Some(Payload::Transaction(_)) => "Transaction",
+            Some(Payload::Milestone(_)) => "Milestone",
+            Some(Payload::Indexation(_)) => "Indexation",
+            Some(Payload::Receipt(_)) => "Receipt",
+            Some(Payload::TreasuryTransaction(_)) => "TreasuryTransaction",
+        };
+
+        format!(
+            "Message(id={}…, parents={}, payload={})",
+            &self.id().0.to_string()[..8],
+            self.parents.len(),
+            payload
+        )
+    }
+
+    /// Returns the proof-of-work score of the message, computed by `compute_pow_score` over the full packed message,
+    /// nonce included. The message without its nonce is hashed with Blake2b-256; that digest and the nonce are
+    /// converted to trits and hashed with Curl-P-81. The score is 3 to the power of the number of trailing zero trits
+    /// of the Curl-P hash, divided by the length of the packed message in bytes.
+    pub fn pow_score(&self) -> f64 {
+        compute_pow_score(&self.pack_new())
+    }
+
+    /// Checks that the proof-of-work score of the message, see `pow_score`, is at least `target_score`, returning
+    /// `Error::InsufficientPowScore` otherwise.
+    pub fn verify_pow(&self, target_score: f64) -> Result<(), Error> {
+        let score = self.pow_score();
+
+        if score < target_score {
+            return Err(Error::InsufficientPowScore(score, target_score));
+        }
 
         Ok(())
     }
 
-    fn unpack<R: Read + ?Sized>(reader: &mut R) -> Result<Self, Self::Error> {
+    /// Reads the payload kind of a packed message without unpacking its payload, `None` if it has no payload.
+    pub fn peek_payload_kind(bytes: &[u8]) -> Result<Option<u32>, Error> {
+        let reader = &mut &bytes[..];
+
+        u64::unpack(reader)?;
+
+        let parents_len = u8::unpack(reader)? as usize;
+
+        if !MESSAGE_PARENTS_RANGE.contains(&parents_len) {
+            return Err(Error::InvalidParentsCount(parents_len));
+        }
+
+        for _ in 0..parents_len {
+            MessageId::unpack(reader)?;
+        }
+
+        if u32::unpack(reader)? == 0 {
+            Ok(None)
+        } else {
+            Ok(Some(u32::unpack(reader)?))
+        }
+    }
+
+    /// Unpacks a message from the start of a reader that may hold trailing bytes, also returning the number of bytes
+    /// consumed.
+    pub fn unpack_with_len<R: Read + ?Sized>(reader: &mut R) -> Result<(Self, usize), Error> {
         let network_id = u64::unpack(reader)?;
 
         let parents = Parents::unpack(reader)?;
@@ -89,7 +191,7 @@ impl Packable for Message {
             return Err(Error::InvalidPayloadKind(payload.unwrap().kind()));
         }
 
-        let nonce = u64::unpack(reader)?;
+        let nonce = Nonce::unpack(reader)?;
 
         // Computed instead of calling `packed_len` on Self because `payload_len` is already known and it may be
         // expensive to call `payload.packed_len()` twice.
@@ -99,17 +201,46 @@ impl Packable for Message {
             return Err(Error::InvalidMessageLength(message_len));
         }
 
+        Ok((
+            Self {
+                network_id,
+                parents,
+                payload,
+                nonce,
+            },
+            message_len,
+        ))
+    }
+}
+
+impl Packable for Message {
+    type Error = Error;
+
+    fn packed_len(&self) -> usize {
+        self.network_id.packed_len()
+            + self.parents.packed_len()
+            + option_payload_packed_len(self.payload.as_ref())
+            + self.nonce.packed_len()
+    }
+
+    fn pack<W: Write>(&self, writer: &mut W) -> Result<(), Self::Error> {
+        self.network_id.pack(writer)?;
+        self.parents.pack(writer)?;
+        option_payload_pack(writer, self.payload.as_ref())?;
+        self.nonce.pack(writer)?;
+
+        Ok(())
+    }
+
+    fn unpack<R: Read + ?Sized>(reader: &mut R) -> Result<Self, Self::Error> {
+        let (message, _) = Self::unpack_with_len(reader)?;
+
         // When parsing the message is complete, there should not be any trailing bytes left that were not parsed.
         if reader.bytes().next().is_some() {
             return Err(Error::RemainingBytesAfterMessage);
         }
 
-        Ok(Self {
-            network_id,
-            parents,
-            payload,
-            nonce,
-        })
+        Ok(message)
     }
 }
 
@@ -173,7 +304,7 @@ impl<P: Provider> MessageBuilder<P> {
             network_id,
             parents,
             payload: self.payload,
-            nonce: 0,
+            nonce: Nonce::default(),
         };
 
         let message_bytes = message.pack_new();
@@ -186,13 +317,15 @@ impl<P: Provider> MessageBuilder<P> {
             self.nonce_provider
                 .unwrap_or((P::Builder::new().finish(), 4000f64, None));
 
-        message.nonce = nonce_provider
-            .nonce(
-                &message_bytes[..message_bytes.len() - std::mem::size_of::<u64>()],
-                target_score,
-                done,
-            )
-            .unwrap_or(0);
+        message.nonce = Nonce::new(
+            nonce_provider
+                .nonce(
+                    &message_bytes[..message_bytes.len() - std::mem::size_of::<u64>()],
+                    target_score,
+                    done,
+                )
+                .unwrap_or(0),
+        );
 
         Ok(message)
     }
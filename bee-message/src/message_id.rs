@@ -20,6 +20,10 @@ impl MessageId {
     pub fn null() -> Self {
         Self([0u8; MESSAGE_ID_LENGTH])
     }
+
+    pub fn is_null(&self) -> bool {
+        self.0 == [0u8; MESSAGE_ID_LENGTH]
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -0,0 +1,56 @@
+// Copyright 2020 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_common::packable::{Packable, Read, Write};
+use bee_pow::score::compute_pow_score;
+
+use alloc::vec::Vec;
+
+/// A wrapper around a `u64` that represents a message nonce, packed as a plain `u64`.
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Nonce(u64);
+
+impl Nonce {
+    pub fn new(value: u64) -> Self {
+        Self(value)
+    }
+
+    /// Returns the PoW score this nonce reaches for `digest_input`, the packed message without its trailing nonce.
+    pub fn difficulty_for(&self, digest_input: &[u8]) -> f64 {
+        let mut bytes = Vec::with_capacity(digest_input.len() + self.packed_len());
+
+        bytes.extend_from_slice(digest_input);
+        bytes.extend_from_slice(&self.0.to_le_bytes());
+
+        compute_pow_score(&bytes)
+    }
+}
+
+impl core::fmt::Display for Nonce {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<Nonce> for u64 {
+    fn from(nonce: Nonce) -> Self {
+        nonce.0
+    }
+}
+
+impl Packable for Nonce {
+    type Error = std::io::Error;
+
+    fn packed_len(&self) -> usize {
+        self.0.packed_len()
+    }
+
+    fn pack<W: Write>(&self, writer: &mut W) -> Result<(), Self::Error> {
+        self.0.pack(writer)
+    }
+
+    fn unpack<R: Read + ?Sized>(reader: &mut R) -> Result<Self, Self::Error> {
+        Ok(Self(u64::unpack(reader)?))
+    }
+}
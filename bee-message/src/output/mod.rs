@@ -13,10 +13,20 @@ pub use signature_locked_single::SignatureLockedSingleOutput;
 pub use storable::{ConsumedOutput, CreatedOutput};
 pub use treasury::{TreasuryOutput, TREASURY_OUTPUT_AMOUNT};
 
-use crate::Error;
+use signature_locked_dust_allowance::SIGNATURE_LOCKED_DUST_ALLOWANCE_OUTPUT_AMOUNT;
+
+use crate::{
+    address::Address,
+    constants::{DUST_ALLOWANCE_DIVISOR, DUST_OUTPUTS_MAX, DUST_THRESHOLD},
+    Error,
+};
 
 use bee_common::packable::{Packable, Read, Write};
 
+use alloc::vec::Vec;
+// TODO no_std
+use std::collections::HashMap;
+
 #[non_exhaustive]
 #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
 #[cfg_attr(
@@ -38,6 +48,82 @@ impl Output {
             Self::Treasury(_) => TreasuryOutput::KIND,
         }
     }
+
+    pub fn amount(&self) -> u64 {
+        match self {
+            Self::SignatureLockedSingle(output) => output.amount(),
+            Self::SignatureLockedDustAllowance(output) => output.amount(),
+            Self::Treasury(output) => output.amount(),
+        }
+    }
+
+    /// Returns the address the output is locked to, `None` for treasury outputs as they are not owned by an address.
+    pub fn address(&self) -> Option<&Address> {
+        match self {
+            Self::SignatureLockedSingle(output) => Some(output.address()),
+            Self::SignatureLockedDustAllowance(output) => Some(output.address()),
+            Self::Treasury(_) => None,
+        }
+    }
+}
+
+/// Sorts outputs lexicographically by their packed bytes, which is the order a transaction essence expects.
+pub fn canonical_sort(outputs: &mut Vec<Output>) {
+    outputs.sort_by_cached_key(Packable::pack_new);
+}
+
+/// Returns the dust allowance an address needs to hold `num_dust_outputs` dust outputs, i.e. one
+/// `DUST_ALLOWANCE_DIVISOR` per output but never less than the minimum amount of a dust allowance output.
+/// Returns `None` above `DUST_OUTPUTS_MAX` dust outputs as no allowance permits that many on an address.
+pub fn required_dust_allowance(num_dust_outputs: usize) -> Option<u64> {
+    if num_dust_outputs == 0 {
+        return Some(0);
+    }
+
+    if num_dust_outputs as u64 > DUST_OUTPUTS_MAX {
+        return None;
+    }
+
+    Some((num_dust_outputs as u64 * DUST_ALLOWANCE_DIVISOR).max(*SIGNATURE_LOCKED_DUST_ALLOWANCE_OUTPUT_AMOUNT.start()))
+}
+
+/// `dust_allowance_sum` is the sum of all the amounts of all unspent SigLockedDustAllowanceOutputs on an address.
+/// The maximum number of allowed dust outputs on this address is `dust_allowance_sum` divided by
+/// `DUST_ALLOWANCE_DIVISOR` and rounded down, i.e. 10 outputs for each 1 Mi deposited.
+/// Regardless of `dust_allowance_sum`, the number of dust outputs must never exceed `DUST_OUTPUTS_MAX` per address.
+pub fn dust_outputs_max(dust_allowance_sum: u64) -> usize {
+    core::cmp::min(dust_allowance_sum / DUST_ALLOWANCE_DIVISOR, DUST_OUTPUTS_MAX) as usize
+}
+
+/// Checks, per address, that the dust outputs of `outputs` fit in the allowance granted by the dust allowance outputs
+/// of `outputs`, reporting the first offending address in output order. Allowances and dust outputs already on the
+/// ledger are not taken into account, which is why this is not part of the syntactic validation of an essence; the
+/// ledger performs the complete check.
+pub fn validate_dust(outputs: &[Output]) -> Result<(), Error> {
+    let mut dust = HashMap::<&Address, (u64, usize)>::new();
+
+    for output in outputs {
+        match output {
+            Output::SignatureLockedSingle(single) if single.amount() < DUST_THRESHOLD => {
+                dust.entry(single.address()).or_default().1 += 1;
+            }
+            Output::SignatureLockedDustAllowance(dust_allowance) => {
+                dust.entry(dust_allowance.address()).or_default().0 += dust_allowance.amount();
+            }
+            _ => {}
+        }
+    }
+
+    // Addresses are checked in the order of the outputs so that the reported violation is deterministic.
+    for address in outputs.iter().filter_map(Output::address) {
+        if let Some((allowance, count)) = dust.get(address) {
+            if *count > dust_outputs_max(*allowance) {
+                return Err(Error::DustAllowanceExceeded(*count, dust_outputs_max(*allowance)));
+            }
+        }
+    }
+
+    Ok(())
 }
 
 impl From<SignatureLockedSingleOutput> for Output {
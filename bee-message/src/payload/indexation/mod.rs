@@ -16,11 +16,13 @@ use core::ops::RangeInclusive;
 
 const INDEX_LENGTH_RANGE: RangeInclusive<usize> = 1..=64;
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IndexationPayload {
     index: Box<[u8]>,
     data: Box<[u8]>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    hashed_index: Option<HashedIndex>,
 }
 
 impl IndexationPayload {
@@ -38,9 +40,22 @@ impl IndexationPayload {
         Ok(Self {
             index: index.into(),
             data: data.into(),
+            hashed_index: None,
         })
     }
 
+    /// Caches an already computed hash of the index so that `hash` doesn't recompute it.
+    /// The hash must be the one of this payload's index, which is checked in debug builds.
+    pub fn with_precomputed_hash(mut self, hashed_index: HashedIndex) -> Self {
+        debug_assert_eq!(
+            hashed_index,
+            HashedIndex::new(Blake2b256::digest(&self.index).into()),
+            "precomputed hash does not match the index"
+        );
+        self.hashed_index = Some(hashed_index);
+        self
+    }
+
     pub fn index(&self) -> &[u8] {
         &self.index
     }
@@ -50,10 +65,19 @@ impl IndexationPayload {
     }
 
     pub fn hash(&self) -> HashedIndex {
-        HashedIndex::new(Blake2b256::digest(&self.index).into())
+        self.hashed_index
+            .unwrap_or_else(|| HashedIndex::new(Blake2b256::digest(&self.index).into()))
+    }
+}
+
+impl PartialEq for IndexationPayload {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index && self.data == other.data
     }
 }
 
+impl Eq for IndexationPayload {}
+
 impl Packable for IndexationPayload {
     type Error = Error;
 
@@ -93,6 +117,7 @@ impl Packable for IndexationPayload {
         Ok(Self {
             index: index.into(),
             data: data.into(),
+            hashed_index: None,
         })
     }
 }
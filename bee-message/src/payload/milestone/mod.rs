@@ -54,7 +54,9 @@ impl MilestonePayload {
             ));
         }
 
-        // TODO check signature length
+        if let Some(signature) = signatures.iter().find(|s| s.len() != MILESTONE_SIGNATURE_LENGTH) {
+            return Err(Error::MilestoneInvalidSignatureLength(signature.len()));
+        }
 
         Ok(Self { essence, signatures })
     }
@@ -17,7 +17,7 @@ use crate::Error;
 
 use bee_common::packable::{Packable, Read, Write};
 
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec::Vec};
 
 #[non_exhaustive]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -44,6 +44,41 @@ impl Payload {
             Self::TreasuryTransaction(_) => TreasuryTransactionPayload::KIND,
         }
     }
+
+    pub fn as_transaction(&self) -> Option<&TransactionPayload> {
+        match self {
+            Self::Transaction(payload) => Some(payload),
+            _ => None,
+        }
+    }
+
+    pub fn as_milestone(&self) -> Option<&MilestonePayload> {
+        match self {
+            Self::Milestone(payload) => Some(payload),
+            _ => None,
+        }
+    }
+
+    pub fn as_indexation(&self) -> Option<&IndexationPayload> {
+        match self {
+            Self::Indexation(payload) => Some(payload),
+            _ => None,
+        }
+    }
+
+    pub fn as_receipt(&self) -> Option<&ReceiptPayload> {
+        match self {
+            Self::Receipt(payload) => Some(payload),
+            _ => None,
+        }
+    }
+
+    pub fn as_treasury_transaction(&self) -> Option<&TreasuryTransactionPayload> {
+        match self {
+            Self::TreasuryTransaction(payload) => Some(payload),
+            _ => None,
+        }
+    }
 }
 
 impl From<TransactionPayload> for Payload {
@@ -157,3 +192,23 @@ pub fn option_payload_unpack<R: Read + ?Sized>(reader: &mut R) -> Result<(usize,
         Ok((0, None))
     }
 }
+
+// Returns the index of the first item that is lexicographically smaller than its predecessor in its serialised form, or
+// also equal to it if `strict` is set. Each item is packed once.
+pub(crate) fn first_unsorted_index<P: Packable>(items: &[P], strict: bool) -> Option<usize> {
+    let mut previous: Option<Vec<u8>> = None;
+
+    for (index, item) in items.iter().enumerate() {
+        let packed = item.pack_new();
+
+        if let Some(previous) = &previous {
+            if *previous > packed || (strict && *previous == packed) {
+                return Some(index);
+            }
+        }
+
+        previous = Some(packed);
+    }
+
+    None
+}
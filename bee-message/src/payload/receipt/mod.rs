@@ -7,14 +7,11 @@ pub use migrated_funds_entry::{MigratedFundsEntry, MIGRATED_FUNDS_ENTRY_AMOUNT};
 
 use crate::{
     milestone::MilestoneIndex,
-    payload::{option_payload_pack, option_payload_packed_len, option_payload_unpack, Payload},
+    payload::{first_unsorted_index, option_payload_pack, option_payload_packed_len, option_payload_unpack, Payload},
     Error,
 };
 
-use bee_common::{
-    ord::is_unique_sorted,
-    packable::{Packable, Read, Write},
-};
+use bee_common::packable::{Packable, Read, Write};
 
 use core::ops::RangeInclusive;
 use std::collections::HashMap;
@@ -49,8 +46,8 @@ impl ReceiptPayload {
         }
 
         // Funds must be lexicographically sorted and unique in their serialised forms.
-        if !is_unique_sorted(funds.iter().map(Packable::pack_new)) {
-            return Err(Error::TransactionOutputsNotSorted);
+        if let Some(index) = first_unsorted_index(&funds, true) {
+            return Err(Error::MigratedFundsNotSorted(index));
         }
 
         // TODO could be merged with the lexicographic check ?
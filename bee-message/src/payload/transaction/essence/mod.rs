@@ -5,12 +5,19 @@ mod regular;
 
 pub use regular::{RegularEssence, RegularEssenceBuilder};
 
-use crate::Error;
+use crate::{
+    address::Address,
+    input::Input,
+    output::{Output, OutputId},
+    Error,
+};
 
 use bee_common::packable::{Packable, Read, Write};
 
 use crypto::hashes::{blake2b::Blake2b256, Digest};
 
+use alloc::vec::Vec;
+
 #[non_exhaustive]
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
@@ -29,8 +36,42 @@ impl Essence {
         }
     }
 
+    /// Returns the packed essence, i.e. the bytes that `hash` digests and that inputs are signed over. Unlike a packed
+    /// transaction payload, it has neither the payload kind prefix nor the unlock blocks.
+    pub fn as_packed(&self) -> Vec<u8> {
+        self.pack_new()
+    }
+
     pub fn hash(&self) -> [u8; 32] {
-        Blake2b256::digest(&self.pack_new()).into()
+        Blake2b256::digest(&self.as_packed()).into()
+    }
+
+    /// Unpacks an essence without its semantic validation, see `RegularEssence::unpack_unvalidated`.
+    /// Only meant for data coming from a trusted source, never for data from the network.
+    pub fn unpack_unvalidated<R: Read + ?Sized>(reader: &mut R) -> Result<Self, Error> {
+        Ok(match u8::unpack(reader)? {
+            RegularEssence::KIND => RegularEssence::unpack_unvalidated(reader)?.into(),
+            k => return Err(Error::InvalidEssenceKind(k)),
+        })
+    }
+
+    pub fn consumed_output_ids(&self) -> Vec<OutputId> {
+        match self {
+            Self::Regular(essence) => essence
+                .inputs()
+                .iter()
+                .filter_map(|input| match input {
+                    Input::UTXO(input) => Some(*input.output_id()),
+                    _ => None,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn output_addresses(&self) -> Vec<&Address> {
+        match self {
+            Self::Regular(essence) => essence.outputs().iter().filter_map(Output::address).collect(),
+        }
     }
 }
 
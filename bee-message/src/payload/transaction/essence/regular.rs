@@ -3,16 +3,13 @@
 
 use crate::{
     constants::{INPUT_OUTPUT_COUNT_RANGE, IOTA_SUPPLY},
-    input::Input,
-    output::Output,
-    payload::{option_payload_pack, option_payload_packed_len, option_payload_unpack, Payload},
+    input::{canonical_sort_inputs, Input},
+    output::{canonical_sort, Output},
+    payload::{first_unsorted_index, option_payload_pack, option_payload_packed_len, option_payload_unpack, Payload},
     Error,
 };
 
-use bee_common::{
-    ord::is_sorted,
-    packable::{Packable, Read, Write},
-};
+use bee_common::packable::{Packable, Read, Write};
 
 use alloc::{boxed::Box, vec::Vec};
 
@@ -42,34 +39,20 @@ impl RegularEssence {
     pub fn payload(&self) -> &Option<Payload> {
         &self.payload
     }
-}
-
-impl Packable for RegularEssence {
-    type Error = Error;
 
-    fn packed_len(&self) -> usize {
-        0u16.packed_len()
-            + self.inputs.iter().map(Packable::packed_len).sum::<usize>()
-            + 0u16.packed_len()
-            + self.outputs.iter().map(Packable::packed_len).sum::<usize>()
-            + option_payload_packed_len(self.payload.as_ref())
-    }
-
-    fn pack<W: Write>(&self, writer: &mut W) -> Result<(), Self::Error> {
-        (self.inputs.len() as u16).pack(writer)?;
-        for input in self.inputs.iter() {
-            input.pack(writer)?;
-        }
-        (self.outputs.len() as u16).pack(writer)?;
-        for output in self.outputs.iter() {
-            output.pack(writer)?;
-        }
-        option_payload_pack(writer, self.payload.as_ref())?;
+    /// Unpacks an essence without its semantic validation (uniqueness, sorting, amounts, kinds).
+    /// Only meant for data coming from a trusted source, e.g. the local storage, never for data from the network.
+    pub fn unpack_unvalidated<R: Read + ?Sized>(reader: &mut R) -> Result<Self, Error> {
+        let builder = Self::unpack_builder(reader)?;
 
-        Ok(())
+        Ok(Self {
+            inputs: builder.inputs.into_boxed_slice(),
+            outputs: builder.outputs.into_boxed_slice(),
+            payload: builder.payload,
+        })
     }
 
-    fn unpack<R: Read + ?Sized>(reader: &mut R) -> Result<Self, Self::Error> {
+    fn unpack_builder<R: Read + ?Sized>(reader: &mut R) -> Result<RegularEssenceBuilder, Error> {
         let inputs_len = u16::unpack(reader)? as usize;
 
         if !INPUT_OUTPUT_COUNT_RANGE.contains(&inputs_len) {
@@ -98,7 +81,37 @@ impl Packable for RegularEssence {
             builder = builder.with_payload(payload);
         }
 
-        builder.finish()
+        Ok(builder)
+    }
+}
+
+impl Packable for RegularEssence {
+    type Error = Error;
+
+    fn packed_len(&self) -> usize {
+        0u16.packed_len()
+            + self.inputs.iter().map(Packable::packed_len).sum::<usize>()
+            + 0u16.packed_len()
+            + self.outputs.iter().map(Packable::packed_len).sum::<usize>()
+            + option_payload_packed_len(self.payload.as_ref())
+    }
+
+    fn pack<W: Write>(&self, writer: &mut W) -> Result<(), Self::Error> {
+        (self.inputs.len() as u16).pack(writer)?;
+        for input in self.inputs.iter() {
+            input.pack(writer)?;
+        }
+        (self.outputs.len() as u16).pack(writer)?;
+        for output in self.outputs.iter() {
+            output.pack(writer)?;
+        }
+        option_payload_pack(writer, self.payload.as_ref())?;
+
+        Ok(())
+    }
+
+    fn unpack<R: Read + ?Sized>(reader: &mut R) -> Result<Self, Self::Error> {
+        Self::unpack_builder(reader)?.finish()
     }
 }
 
@@ -107,6 +120,7 @@ pub struct RegularEssenceBuilder {
     pub(crate) inputs: Vec<Input>,
     pub(crate) outputs: Vec<Output>,
     pub(crate) payload: Option<Payload>,
+    pub(crate) sorted: bool,
 }
 
 impl RegularEssenceBuilder {
@@ -139,7 +153,18 @@ impl RegularEssenceBuilder {
         self
     }
 
-    pub fn finish(self) -> Result<RegularEssence, Error> {
+    /// Makes `finish` sort the inputs and outputs by their packed bytes instead of requiring them to be sorted.
+    pub fn with_sorted(mut self) -> Self {
+        self.sorted = true;
+        self
+    }
+
+    pub fn finish(mut self) -> Result<RegularEssence, Error> {
+        if self.sorted {
+            canonical_sort_inputs(&mut self.inputs);
+            canonical_sort(&mut self.outputs);
+        }
+
         if !INPUT_OUTPUT_COUNT_RANGE.contains(&self.inputs.len()) {
             return Err(Error::InvalidInputOutputCount(self.inputs.len()));
         }
@@ -168,8 +193,8 @@ impl RegularEssenceBuilder {
         }
 
         // Inputs must be lexicographically sorted in their serialised forms.
-        if !is_sorted(self.inputs.iter().map(Packable::pack_new)) {
-            return Err(Error::TransactionInputsNotSorted);
+        if let Some(index) = first_unsorted_index(&self.inputs, false) {
+            return Err(Error::TransactionInputsNotSorted(index));
         }
 
         // Outputs validation
@@ -224,8 +249,8 @@ impl RegularEssenceBuilder {
         }
 
         // Outputs must be lexicographically sorted in their serialised forms.
-        if !is_sorted(self.outputs.iter().map(Packable::pack_new)) {
-            return Err(Error::TransactionOutputsNotSorted);
+        if let Some(index) = first_unsorted_index(&self.outputs, false) {
+            return Err(Error::TransactionOutputsNotSorted(index));
         }
 
         Ok(RegularEssence {
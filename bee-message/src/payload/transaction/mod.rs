@@ -4,14 +4,26 @@
 mod essence;
 mod transaction_id;
 
-use crate::{unlock::UnlockBlocks, Error};
+use crate::{
+    address::Address,
+    output::{Output, OutputId},
+    unlock::{Ed25519Signature, ReferenceUnlock, SignatureUnlock, UnlockBlock, UnlockBlocks},
+    Error,
+};
 
 pub use essence::{Essence, RegularEssence, RegularEssenceBuilder};
 pub use transaction_id::{TransactionId, TRANSACTION_ID_LENGTH};
 
 use bee_common::packable::{Packable, Read, Write};
 
-use crypto::hashes::{blake2b::Blake2b256, Digest};
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    signatures::ed25519::SecretKey,
+};
+
+use alloc::{boxed::Box, vec::Vec};
+// TODO no_std
+use std::collections::HashMap;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -43,6 +55,61 @@ impl TransactionPayload {
     pub fn unlock_blocks(&self) -> &UnlockBlocks {
         &self.unlock_blocks
     }
+
+    /// Verifies every unlock block against the essence hash, references being resolved to the signature they point to.
+    /// `input_addresses` holds, in input order, the address of the output consumed by each input; it is not part of
+    /// the transaction and has to be resolved from the ledger by the caller.
+    pub fn verify_signatures(&self, input_addresses: &[Address]) -> Result<(), Error> {
+        if input_addresses.len() != self.unlock_blocks.len() {
+            return Err(Error::InputUnlockBlockCountMismatch(
+                input_addresses.len(),
+                self.unlock_blocks.len(),
+            ));
+        }
+
+        let hash = self.essence.hash();
+
+        for (index, address) in input_addresses.iter().enumerate() {
+            // `UnlockBlocks::get` resolves references to the signature unlock block they point to.
+            match self.unlock_blocks.get(index) {
+                Some(UnlockBlock::Signature(signature)) => address.verify(&hash, signature)?,
+                _ => return Err(Error::InvalidUnlockBlockReference(index)),
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn created_outputs(&self) -> Vec<(OutputId, &Output)> {
+        let id = self.id();
+
+        match self.essence {
+            Essence::Regular(ref essence) => essence
+                .outputs()
+                .iter()
+                .enumerate()
+                // Unwrap is fine because the number of outputs has already been validated.
+                .map(|(index, output)| (OutputId::new(id, index as u16).unwrap(), output))
+                .collect(),
+        }
+    }
+}
+
+/// Checks that no output is consumed by more than one transaction of a block. Only conflicts between the
+/// transactions are detected, their signatures are checked separately by `TransactionPayload::verify_signatures` as it
+/// needs the addresses of the consumed outputs.
+pub fn validate_block(transactions: &[&TransactionPayload]) -> Result<(), Error> {
+    let mut consumers = HashMap::new();
+
+    for (index, transaction) in transactions.iter().enumerate() {
+        for output_id in transaction.essence().consumed_output_ids() {
+            if let Some(previous) = consumers.insert(output_id, index) {
+                return Err(Error::ConflictingInputs(output_id, previous, index));
+            }
+        }
+    }
+
+    Ok(())
 }
 
 impl Packable for TransactionPayload {
@@ -93,6 +160,36 @@ impl TransactionPayloadBuilder {
         self
     }
 
+    /// Sets the essence and derives its unlock blocks from the keys of the addresses owning each input, in input order.
+    /// The essence hash is signed once per distinct address, later inputs of the same address get a reference unlock.
+    pub fn with_signatures(mut self, essence: Essence, keyed_inputs: &[(Address, SecretKey)]) -> Result<Self, Error> {
+        let hash = essence.hash();
+        let mut signature_indexes = HashMap::new();
+        let mut unlock_blocks = Vec::with_capacity(keyed_inputs.len());
+
+        for (index, (address, secret_key)) in keyed_inputs.iter().enumerate() {
+            if let Some(signature_index) = signature_indexes.get(address) {
+                unlock_blocks.push(UnlockBlock::from(ReferenceUnlock::new(*signature_index)?));
+                continue;
+            }
+
+            let signature = SignatureUnlock::from(Ed25519Signature::new(
+                secret_key.public_key().to_compressed_bytes(),
+                Box::new(secret_key.sign(&hash).to_bytes()),
+            ));
+
+            address.verify(&hash, &signature)?;
+
+            signature_indexes.insert(address, index as u16);
+            unlock_blocks.push(UnlockBlock::from(signature));
+        }
+
+        self.essence.replace(essence);
+        self.unlock_blocks.replace(UnlockBlocks::new(unlock_blocks)?);
+
+        Ok(self)
+    }
+
     pub fn finish(self) -> Result<TransactionPayload, Error> {
         let essence = self.essence.ok_or(Error::MissingField("essence"))?;
         let unlock_blocks = self.unlock_blocks.ok_or(Error::MissingField("unlock_blocks"))?;
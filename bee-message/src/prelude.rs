@@ -25,5 +25,6 @@ pub use crate::{
         Payload,
     },
     unlock::{Ed25519Signature, ReferenceUnlock, SignatureUnlock, UnlockBlock, UnlockBlocks},
-    Error, Message, MessageBuilder, MessageId, Parents, MESSAGE_ID_LENGTH, MESSAGE_LENGTH_MAX, MESSAGE_LENGTH_MIN,
+    Error, Message, MessageBuilder, MessageId, Nonce, Parents, StorageEntries, MESSAGE_ID_LENGTH, MESSAGE_LENGTH_MAX,
+    MESSAGE_LENGTH_MIN,
 };
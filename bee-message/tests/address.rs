@@ -4,6 +4,8 @@
 use bee_common::packable::Packable;
 use bee_message::prelude::*;
 
+use bech32::{ToBase32, Variant};
+
 use core::{convert::TryInto, str::FromStr};
 
 const ED25519_ADDRESS: &str = "52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649";
@@ -58,3 +60,114 @@ fn bech32_string_to_address() {
         panic!("Expecting an Ed25519 address");
     }
 }
+
+#[test]
+fn bech32_round_trip() {
+    let address = Address::from(Ed25519Address::from_str(ED25519_ADDRESS).unwrap());
+
+    assert_eq!(Address::try_from_bech32(&address.to_bech32("iota")).unwrap(), address);
+}
+
+#[test]
+fn bech32_invalid_mixed_case() {
+    let address = Address::from(Ed25519Address::from_str(ED25519_ADDRESS).unwrap()).to_bech32("iota");
+    let mixed_case = address[..5].to_owned() + &address[5..].to_uppercase();
+
+    assert!(matches!(
+        Address::try_from_bech32(&mixed_case),
+        Err(Error::InvalidAddress)
+    ));
+}
+
+#[test]
+fn bech32_invalid_too_long() {
+    let address = bech32::encode("iota", [0u8; 64].to_base32(), Variant::Bech32).unwrap();
+
+    assert!(address.len() > 90);
+    assert!(matches!(Address::try_from_bech32(&address), Err(Error::InvalidAddress)));
+}
+
+#[test]
+fn bech32_invalid_too_long_hrp() {
+    let address = Address::from(Ed25519Address::from_str(ED25519_ADDRESS).unwrap());
+    let hrp = "a".repeat(40);
+    let encoded = address.to_bech32(&hrp);
+
+    assert!(encoded.len() > 90);
+    assert_eq!(bech32::decode(&encoded).unwrap().0, hrp);
+    assert!(matches!(Address::try_from_bech32(&encoded), Err(Error::InvalidAddress)));
+}
+
+#[test]
+fn bech32_invalid_payload_length() {
+    let mut bytes = Address::from(Ed25519Address::from_str(ED25519_ADDRESS).unwrap()).pack_new();
+
+    let too_short = bech32::encode("iota", bytes[..20].to_base32(), Variant::Bech32).unwrap();
+    assert!(matches!(
+        Address::try_from_bech32(&too_short),
+        Err(Error::InvalidAddress)
+    ));
+
+    bytes.push(0);
+    let too_long = bech32::encode("iota", bytes.to_base32(), Variant::Bech32).unwrap();
+    assert!(matches!(
+        Address::try_from_bech32(&too_long),
+        Err(Error::InvalidAddress)
+    ));
+}
+
+#[test]
+fn bech32_with_hrp_valid() {
+    let address = Address::from(Ed25519Address::from_str(ED25519_ADDRESS).unwrap());
+
+    assert_eq!(
+        Address::try_from_bech32_with_hrp(&address.to_bech32("atoi"), "atoi").unwrap(),
+        address
+    );
+}
+
+#[test]
+fn bech32_with_hrp_invalid_hrp() {
+    let address = Address::from(Ed25519Address::from_str(ED25519_ADDRESS).unwrap());
+
+    assert!(matches!(
+        Address::try_from_bech32_with_hrp(&address.to_bech32("atoi"), "iota"),
+        Err(Error::InvalidAddress)
+    ));
+}
+
+#[test]
+fn bech32_invalid_checksum() {
+    assert!(matches!(
+        Address::try_from_bech32("iota1qpf0mlq8yxpx2nck8a0slxnzr4ef2ek8f5gqxlzd0wasgp73utryj430ldv"),
+        Err(Error::InvalidAddress)
+    ));
+}
+
+#[test]
+fn bech32m_round_trip() {
+    let address = Address::from(Ed25519Address::from_str(ED25519_ADDRESS).unwrap());
+    let bech32m = address.to_bech32m("iota");
+
+    assert_ne!(bech32m, address.to_bech32("iota"));
+    assert_eq!(Address::try_from_bech32m(&bech32m).unwrap(), address);
+    assert_eq!(Address::try_from_bech32(&bech32m).unwrap(), address);
+}
+
+#[test]
+fn bech32m_rejected_when_strict_bech32() {
+    let address = Address::from(Ed25519Address::from_str(ED25519_ADDRESS).unwrap());
+
+    assert!(matches!(
+        Address::try_from_bech32_strict(&address.to_bech32m("iota")),
+        Err(Error::InvalidAddress)
+    ));
+    assert!(matches!(
+        Address::try_from_bech32m(&address.to_bech32("iota")),
+        Err(Error::InvalidAddress)
+    ));
+    assert_eq!(
+        Address::try_from_bech32_strict(&address.to_bech32("iota")).unwrap(),
+        address
+    );
+}
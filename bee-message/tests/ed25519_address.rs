@@ -4,7 +4,10 @@
 use bee_common::packable::Packable;
 use bee_message::prelude::*;
 
-use core::{convert::TryInto, str::FromStr};
+use core::{
+    convert::{TryFrom, TryInto},
+    str::FromStr,
+};
 
 const ED25519_ADDRESS: &str = "52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649";
 const ED25519_ADDRESS_INVALID_HEX: &str = "52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c64x";
@@ -47,6 +50,27 @@ fn from_str_invalid_len() {
     ));
 }
 
+#[test]
+fn try_from_bytes_valid() {
+    let bytes = [42u8; ED25519_ADDRESS_LENGTH];
+
+    assert_eq!(
+        Ed25519Address::try_from(&bytes[..]).unwrap(),
+        Ed25519Address::new(bytes)
+    );
+}
+
+#[test]
+fn try_from_bytes_invalid_len() {
+    let bytes = [42u8; ED25519_ADDRESS_LENGTH - 1];
+
+    assert!(matches!(
+        Ed25519Address::try_from(&bytes[..]),
+        Err(Error::InvalidAddressLength(expected, actual))
+            if expected == ED25519_ADDRESS_LENGTH && actual == ED25519_ADDRESS_LENGTH - 1
+    ));
+}
+
 #[test]
 fn from_to_str() {
     assert_eq!(
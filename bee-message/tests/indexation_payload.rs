@@ -65,6 +65,13 @@ fn packed_len() {
     );
 }
 
+#[test]
+fn packed_len_large() {
+    let indexation = IndexationPayload::new(&rand_bytes(64), &rand_bytes(MESSAGE_LENGTH_MAX)).unwrap();
+
+    assert_eq!(indexation.packed_len(), indexation.pack_new().len());
+}
+
 #[test]
 fn pack_unpack_valid() {
     let indexation_1 =
@@ -108,3 +115,25 @@ fn unpack_invalid_data_length_more_than_max() {
         Err(Error::InvalidIndexationDataLength(33333))
     ));
 }
+
+#[test]
+fn with_precomputed_hash() {
+    let index = rand_bytes_32();
+    let data = [0x42, 0xff, 0x84, 0xa2, 0x42, 0xff, 0x84, 0xa2];
+    let indexation = IndexationPayload::new(&index, &data).unwrap();
+    let hash = indexation.hash();
+    let cached = indexation.clone().with_precomputed_hash(hash);
+
+    assert_eq!(cached.hash(), hash);
+    assert_eq!(cached.hash(), IndexationPayload::new(&index, &data).unwrap().hash());
+    assert_eq!(cached, indexation);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "precomputed hash does not match the index")]
+fn with_precomputed_hash_mismatch() {
+    IndexationPayload::new(&rand_bytes_32(), &[0x42, 0xff, 0x84, 0xa2])
+        .unwrap()
+        .with_precomputed_hash(HashedIndex::new([0u8; HASHED_INDEX_LENGTH]));
+}
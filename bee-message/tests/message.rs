@@ -7,7 +7,17 @@ use bee_pow::{
     providers::{ConstantBuilder, Miner, MinerBuilder, ProviderBuilder},
     score::compute_pow_score,
 };
-use bee_test::rand::message::rand_message_ids;
+use bee_test::{
+    packable::CountingWriter,
+    rand::{
+        address::rand_address,
+        message::{rand_indexation, rand_message, rand_message_id, rand_message_ids, rand_message_with_parents},
+        parents::rand_parents,
+        transaction::rand_transaction_id,
+    },
+};
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
 
 #[test]
 fn pow_default_provider() {
@@ -87,3 +97,280 @@ fn unpack_invalid_remaining_bytes() {
         Err(Error::RemainingBytesAfterMessage)
     ))
 }
+
+#[test]
+fn packed_len_matches_pack() {
+    let message = rand_message();
+    let mut writer = CountingWriter::new();
+
+    message.pack(&mut writer).unwrap();
+
+    assert_eq!(writer.count(), message.packed_len());
+}
+
+#[test]
+fn unpack_with_len_remaining_bytes() {
+    let message = rand_message();
+    let mut bytes = message.pack_new();
+    bytes.extend_from_slice(&[42, 42, 42]);
+
+    let (unpacked, len) = Message::unpack_with_len(&mut bytes.as_slice()).unwrap();
+
+    assert_eq!(len, message.packed_len());
+    assert_eq!(unpacked.pack_new(), message.pack_new());
+}
+
+#[test]
+fn verify_pow_valid() {
+    let message = MessageBuilder::new()
+        .with_network_id(0)
+        .with_parents(Parents::new(rand_message_ids(2)).unwrap())
+        .with_nonce_provider(
+            MinerBuilder::new().with_num_workers(num_cpus::get()).finish(),
+            10000f64,
+            None,
+        )
+        .finish()
+        .unwrap();
+
+    assert!(message.pow_score() >= 10000f64);
+    assert!(message.verify_pow(10000f64).is_ok());
+}
+
+#[test]
+fn verify_pow_invalid() {
+    let message = MessageBuilder::new()
+        .with_network_id(0)
+        .with_parents(Parents::new(rand_message_ids(2)).unwrap())
+        .with_nonce_provider(ConstantBuilder::new().with_value(0).finish(), 10000f64, None)
+        .finish()
+        .unwrap();
+
+    assert!(matches!(
+        message.verify_pow(10000f64),
+        Err(Error::InsufficientPowScore(score, target)) if score < target && target == 10000f64
+    ));
+}
+
+#[test]
+fn summary() {
+    let message = MessageBuilder::new()
+        .with_network_id(0)
+        .with_parents(rand_parents())
+        .with_payload(Payload::Indexation(Box::new(rand_indexation())))
+        .with_nonce_provider(ConstantBuilder::new().with_value(0).finish(), 0f64, None)
+        .finish()
+        .unwrap();
+    let summary = message.summary();
+
+    assert!(summary.starts_with(&format!("Message(id={}…", &message.id().0.to_string()[..8])));
+    assert!(summary.contains(&format!("parents={}", message.parents().len())));
+    assert!(summary.contains("payload=Indexation"));
+}
+
+#[test]
+fn storage_entries_indexation() {
+    let indexation = IndexationPayload::new(b"index", b"data").unwrap();
+    let message = MessageBuilder::new()
+        .with_network_id(0)
+        .with_parents(Parents::new(rand_message_ids(2)).unwrap())
+        .with_payload(Payload::Indexation(Box::new(indexation.clone())))
+        .with_nonce_provider(ConstantBuilder::new().with_value(0).finish(), 0f64, None)
+        .finish()
+        .unwrap();
+    let entries = message.storage_entries();
+
+    assert_eq!(*entries.message_id(), message.id().0);
+    assert_eq!(entries.bytes(), message.pack_new().as_slice());
+    assert_eq!(entries.hashed_index(), Some(&indexation.hash()));
+}
+
+#[test]
+fn storage_entries_transaction() {
+    let transaction = TransactionPayload::builder()
+        .with_essence(
+            RegularEssence::builder()
+                .add_input(UTXOInput::new(rand_transaction_id(), 0).unwrap().into())
+                .add_output(SignatureLockedSingleOutput::new(rand_address(), 1_000).unwrap().into())
+                .finish()
+                .unwrap()
+                .into(),
+        )
+        .with_unlock_blocks(
+            UnlockBlocks::new(vec![UnlockBlock::Signature(SignatureUnlock::Ed25519(
+                Ed25519Signature::new([0; 32], Box::new([0; 64])),
+            ))])
+            .unwrap(),
+        )
+        .finish()
+        .unwrap();
+    let message = MessageBuilder::new()
+        .with_network_id(0)
+        .with_parents(Parents::new(rand_message_ids(2)).unwrap())
+        .with_payload(Payload::Transaction(Box::new(transaction)))
+        .with_nonce_provider(ConstantBuilder::new().with_value(0).finish(), 0f64, None)
+        .finish()
+        .unwrap();
+    let entries = message.storage_entries();
+
+    assert_eq!(*entries.message_id(), message.id().0);
+    assert!(entries.hashed_index().is_none());
+}
+
+#[test]
+fn storage_entries_transaction_indexation() {
+    let indexation = IndexationPayload::new(b"index", b"data").unwrap();
+    let transaction = TransactionPayload::builder()
+        .with_essence(
+            RegularEssence::builder()
+                .add_input(UTXOInput::new(rand_transaction_id(), 0).unwrap().into())
+                .add_output(SignatureLockedSingleOutput::new(rand_address(), 1_000).unwrap().into())
+                .with_payload(Payload::Indexation(Box::new(indexation.clone())))
+                .finish()
+                .unwrap()
+                .into(),
+        )
+        .with_unlock_blocks(
+            UnlockBlocks::new(vec![UnlockBlock::Signature(SignatureUnlock::Ed25519(
+                Ed25519Signature::new([0; 32], Box::new([0; 64])),
+            ))])
+            .unwrap(),
+        )
+        .finish()
+        .unwrap();
+    let message = MessageBuilder::new()
+        .with_network_id(0)
+        .with_parents(Parents::new(rand_message_ids(2)).unwrap())
+        .with_payload(Payload::Transaction(Box::new(transaction)))
+        .with_nonce_provider(ConstantBuilder::new().with_value(0).finish(), 0f64, None)
+        .finish()
+        .unwrap();
+    let entries = message.storage_entries();
+
+    assert_eq!(*entries.message_id(), message.id().0);
+    assert_eq!(entries.hashed_index(), Some(&indexation.hash()));
+}
+
+#[test]
+fn id_matches_single_pack() {
+    let message = rand_message();
+    let bytes = message.pack_new();
+    let (id, id_bytes) = message.id();
+
+    assert_eq!(id_bytes, bytes);
+    assert_eq!(id, MessageId::new(Blake2b256::digest(&bytes).into()));
+}
+
+#[test]
+fn nonce_difficulty_matches_pow_score() {
+    let message = MessageBuilder::new()
+        .with_network_id(0)
+        .with_parents(Parents::new(rand_message_ids(2)).unwrap())
+        .with_nonce_provider(
+            MinerBuilder::new().with_num_workers(num_cpus::get()).finish(),
+            100f64,
+            None,
+        )
+        .finish()
+        .unwrap();
+    let bytes = message.pack_new();
+
+    assert_eq!(
+        message
+            .nonce()
+            .difficulty_for(&bytes[..bytes.len() - std::mem::size_of::<u64>()]),
+        message.pow_score()
+    );
+}
+
+#[test]
+fn nonce_from_provider() {
+    let message = MessageBuilder::new()
+        .with_network_id(0)
+        .with_parents(rand_parents())
+        .with_nonce_provider(ConstantBuilder::new().with_value(42).finish(), 0f64, None)
+        .finish()
+        .unwrap();
+    let bytes = message.pack_new();
+
+    assert_eq!(message.nonce(), Nonce::new(42));
+    assert_eq!(
+        message.nonce().pack_new(),
+        bytes[bytes.len() - std::mem::size_of::<u64>()..]
+    );
+}
+
+#[test]
+fn peek_payload_kind() {
+    let transaction = TransactionPayload::builder()
+        .with_essence(
+            RegularEssence::builder()
+                .add_input(UTXOInput::new(rand_transaction_id(), 0).unwrap().into())
+                .add_output(SignatureLockedSingleOutput::new(rand_address(), 1_000).unwrap().into())
+                .finish()
+                .unwrap()
+                .into(),
+        )
+        .with_unlock_blocks(
+            UnlockBlocks::new(vec![UnlockBlock::Signature(SignatureUnlock::Ed25519(
+                Ed25519Signature::new([0; 32], Box::new([0; 64])),
+            ))])
+            .unwrap(),
+        )
+        .finish()
+        .unwrap();
+    let milestone = MilestonePayload::new(
+        MilestonePayloadEssence::new(
+            MilestoneIndex(0),
+            0,
+            rand_parents(),
+            [0; MILESTONE_MERKLE_PROOF_LENGTH],
+            vec![[0; 32]],
+            None,
+        )
+        .unwrap(),
+        vec![Box::new([0; 64])],
+    )
+    .unwrap();
+    let payloads = vec![
+        None,
+        Some(Payload::from(transaction)),
+        Some(Payload::from(milestone)),
+        Some(Payload::from(rand_indexation())),
+    ];
+
+    for payload in payloads {
+        let mut builder = MessageBuilder::new()
+            .with_network_id(0)
+            .with_parents(rand_parents())
+            .with_nonce_provider(ConstantBuilder::new().with_value(0).finish(), 0f64, None);
+        if let Some(payload) = payload {
+            builder = builder.with_payload(payload);
+        }
+        let bytes = builder.finish().unwrap().pack_new();
+        let message = Message::unpack(&mut bytes.as_slice()).unwrap();
+
+        assert_eq!(
+            Message::peek_payload_kind(&bytes).unwrap(),
+            message.payload().as_ref().map(Payload::kind)
+        );
+    }
+}
+
+#[test]
+fn peek_payload_kind_truncated() {
+    let bytes = rand_message().pack_new();
+
+    assert!(Message::peek_payload_kind(&bytes[..8]).is_err());
+}
+
+#[test]
+fn approves() {
+    let parents = Parents::new(rand_message_ids(3)).unwrap();
+    let message = rand_message_with_parents(parents.clone());
+
+    for parent in parents.iter() {
+        assert!(message.approves(parent));
+    }
+    assert!(!message.approves(&rand_message_id()));
+}
@@ -42,3 +42,21 @@ fn from_to_str() {
 fn packed_len() {
     assert_eq!(MessageId::from_str(MESSAGE_ID).unwrap().packed_len(), 32);
 }
+
+#[test]
+fn null() {
+    let message_id = MessageId::null();
+
+    assert!(message_id.is_null());
+    assert_eq!(message_id, MessageId::new([0u8; MESSAGE_ID_LENGTH]));
+    assert!(!MessageId::from_str(MESSAGE_ID).unwrap().is_null());
+}
+
+#[test]
+fn pack_unpack_null() {
+    let message_id_1 = MessageId::null();
+    let message_id_2 = MessageId::unpack(&mut message_id_1.pack_new().as_slice()).unwrap();
+
+    assert_eq!(message_id_1, message_id_2);
+    assert!(message_id_2.is_null());
+}
@@ -65,3 +65,22 @@ fn new_invalid_public_keys_sgnatures_count_mismatch() {
         Err(Error::MilestonePublicKeysSignaturesCountMismatch(2, 3))
     ));
 }
+
+#[test]
+fn new_invalid_signature_length() {
+    assert!(matches!(
+        MilestonePayload::new(
+            MilestonePayloadEssence::new(
+                MilestoneIndex(0),
+                0,
+                rand_parents(),
+                [0; MILESTONE_MERKLE_PROOF_LENGTH],
+                vec![[0; 32]],
+                None,
+            )
+            .unwrap(),
+            vec![Box::new([0; 63])],
+        ),
+        Err(Error::MilestoneInvalidSignatureLength(63))
+    ));
+}
@@ -0,0 +1,143 @@
+// Copyright 2020 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::{
+    constants::{DUST_ALLOWANCE_DIVISOR, DUST_OUTPUTS_MAX, DUST_THRESHOLD},
+    output,
+    prelude::*,
+};
+use bee_test::rand::address::rand_address;
+
+use core::str::FromStr;
+
+const ED25519_ADDRESS: &str = "52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649";
+
+#[test]
+fn signature_locked_single_accessors() {
+    let single =
+        SignatureLockedSingleOutput::new(Address::from(Ed25519Address::from_str(ED25519_ADDRESS).unwrap()), 1_000)
+            .unwrap();
+    let output = Output::from(single.clone());
+
+    assert_eq!(output.amount(), single.amount());
+    assert_eq!(output.address(), Some(single.address()));
+}
+
+#[test]
+fn signature_locked_dust_allowance_accessors() {
+    let dust_allowance = SignatureLockedDustAllowanceOutput::new(
+        Address::from(Ed25519Address::from_str(ED25519_ADDRESS).unwrap()),
+        1_000_000,
+    )
+    .unwrap();
+    let output = Output::from(dust_allowance.clone());
+
+    assert_eq!(output.amount(), dust_allowance.amount());
+    assert_eq!(output.address(), Some(dust_allowance.address()));
+}
+
+#[test]
+fn treasury_accessors() {
+    let treasury = TreasuryOutput::new(1_000).unwrap();
+    let output = Output::from(treasury.clone());
+
+    assert_eq!(output.amount(), treasury.amount());
+    assert_eq!(output.address(), None);
+}
+
+#[test]
+fn required_dust_allowance_values() {
+    assert_eq!(output::required_dust_allowance(0), Some(0));
+    assert_eq!(output::required_dust_allowance(1), Some(1_000_000));
+    assert_eq!(output::required_dust_allowance(10), Some(1_000_000));
+    assert_eq!(output::required_dust_allowance(11), Some(1_100_000));
+    assert_eq!(output::required_dust_allowance(100), Some(10_000_000));
+    assert_eq!(output::required_dust_allowance(DUST_OUTPUTS_MAX as usize + 1), None);
+}
+
+#[test]
+fn required_dust_allowance_is_minimal() {
+    for n in 1..=DUST_OUTPUTS_MAX as usize {
+        let allowance = output::required_dust_allowance(n).unwrap();
+
+        assert!(SignatureLockedDustAllowanceOutput::new(rand_address(), allowance).is_ok());
+        assert!((allowance / DUST_ALLOWANCE_DIVISOR) as usize >= n);
+        if allowance > 1_000_000 {
+            assert!((((allowance - 1) / DUST_ALLOWANCE_DIVISOR) as usize) < n);
+        }
+    }
+}
+
+#[test]
+fn validate_dust_within_allowance() {
+    let address = rand_address();
+    let outputs = vec![
+        SignatureLockedSingleOutput::new(address, 1).unwrap().into(),
+        SignatureLockedDustAllowanceOutput::new(address, output::required_dust_allowance(1).unwrap())
+            .unwrap()
+            .into(),
+        SignatureLockedSingleOutput::new(rand_address(), DUST_THRESHOLD)
+            .unwrap()
+            .into(),
+    ];
+
+    assert!(output::validate_dust(&outputs).is_ok());
+}
+
+#[test]
+fn validate_dust_exactly_required_allowance() {
+    for n in [10, 11, 25, 50, 99, 100].iter().copied() {
+        let address = rand_address();
+        let mut outputs: Vec<Output> =
+            vec![
+                SignatureLockedDustAllowanceOutput::new(address, output::required_dust_allowance(n).unwrap())
+                    .unwrap()
+                    .into(),
+            ];
+        outputs.extend((0..n).map(|_| Output::from(SignatureLockedSingleOutput::new(address, 1).unwrap())));
+
+        assert!(output::validate_dust(&outputs).is_ok());
+
+        outputs.push(SignatureLockedSingleOutput::new(address, 1).unwrap().into());
+
+        assert!(matches!(
+            output::validate_dust(&outputs),
+            Err(Error::DustAllowanceExceeded(count, max)) if count == n + 1 && max == n
+        ));
+    }
+}
+
+#[test]
+fn validate_dust_allowance_exceeded() {
+    let outputs = vec![
+        SignatureLockedSingleOutput::new(rand_address(), 1).unwrap().into(),
+        SignatureLockedDustAllowanceOutput::new(rand_address(), 1_000_000)
+            .unwrap()
+            .into(),
+    ];
+
+    assert!(matches!(
+        output::validate_dust(&outputs),
+        Err(Error::DustAllowanceExceeded(1, 0))
+    ));
+}
+
+#[test]
+fn validate_dust_first_violation_in_output_order() {
+    let address_1 = rand_address();
+    let address_2 = rand_address();
+    let outputs = vec![
+        SignatureLockedSingleOutput::new(address_1, 1).unwrap().into(),
+        SignatureLockedSingleOutput::new(address_2, 1).unwrap().into(),
+        SignatureLockedSingleOutput::new(address_2, 2).unwrap().into(),
+    ];
+
+    assert!(matches!(
+        output::validate_dust(&outputs),
+        Err(Error::DustAllowanceExceeded(1, 0))
+    ));
+    assert!(matches!(
+        output::validate_dust(&outputs[1..]),
+        Err(Error::DustAllowanceExceeded(2, 0))
+    ));
+}
@@ -67,6 +67,19 @@ fn pack_unpack_valid() {
     assert_eq!(parents_1, parents_2);
 }
 
+#[test]
+fn pack_unpack_valid_all_counts() {
+    for count in 1..=8 {
+        let parents_1 = Parents::new(rand_message_ids(count)).unwrap();
+        let packed_parents = parents_1.pack_new();
+        let parents_2 = Parents::unpack(&mut packed_parents.as_slice()).unwrap();
+
+        assert_eq!(packed_parents.len(), parents_1.packed_len());
+        assert_eq!(packed_parents.len(), 1 + count * MESSAGE_ID_LENGTH);
+        assert_eq!(parents_1, parents_2);
+    }
+}
+
 #[test]
 fn pack_unpack_invalid_less_than_min() {
     let bytes = vec![
@@ -0,0 +1,93 @@
+// Copyright 2020 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::prelude::*;
+use bee_test::rand::{
+    address::rand_address,
+    input::rand_utxo_input,
+    message::{rand_indexation, rand_message_id},
+    parents::rand_parents,
+};
+
+fn treasury_transaction() -> TreasuryTransactionPayload {
+    TreasuryTransactionPayload::new(
+        TreasuryInput::new(rand_message_id()).into(),
+        TreasuryOutput::new(1_000_000).unwrap().into(),
+    )
+    .unwrap()
+}
+
+fn payloads() -> Vec<Payload> {
+    let transaction = TransactionPayload::builder()
+        .with_essence(
+            RegularEssence::builder()
+                .add_input(rand_utxo_input().into())
+                .add_output(SignatureLockedSingleOutput::new(rand_address(), 1_000).unwrap().into())
+                .finish()
+                .unwrap()
+                .into(),
+        )
+        .with_unlock_blocks(
+            UnlockBlocks::new(vec![UnlockBlock::Signature(SignatureUnlock::Ed25519(
+                Ed25519Signature::new([0; 32], Box::new([0; 64])),
+            ))])
+            .unwrap(),
+        )
+        .finish()
+        .unwrap();
+    let milestone = MilestonePayload::new(
+        MilestonePayloadEssence::new(
+            MilestoneIndex(0),
+            0,
+            rand_parents(),
+            [0; MILESTONE_MERKLE_PROOF_LENGTH],
+            vec![[0; 32]],
+            None,
+        )
+        .unwrap(),
+        vec![Box::new([0; 64])],
+    )
+    .unwrap();
+    let receipt = ReceiptPayload::new(
+        MilestoneIndex(0),
+        true,
+        vec![MigratedFundsEntry::new(
+            [0; 49],
+            SignatureLockedSingleOutput::new(rand_address(), 1_000_000).unwrap(),
+        )
+        .unwrap()],
+        treasury_transaction().into(),
+    )
+    .unwrap();
+
+    vec![
+        transaction.into(),
+        milestone.into(),
+        rand_indexation().into(),
+        receipt.into(),
+        treasury_transaction().into(),
+    ]
+}
+
+#[test]
+fn as_accessors() {
+    for payload in payloads() {
+        assert_eq!(
+            payload.as_transaction().is_some(),
+            payload.kind() == TransactionPayload::KIND
+        );
+        assert_eq!(
+            payload.as_milestone().is_some(),
+            payload.kind() == MilestonePayload::KIND
+        );
+        assert_eq!(
+            payload.as_indexation().is_some(),
+            payload.kind() == IndexationPayload::KIND
+        );
+        assert_eq!(payload.as_receipt().is_some(), payload.kind() == ReceiptPayload::KIND);
+        assert_eq!(
+            payload.as_treasury_transaction().is_some(),
+            payload.kind() == TreasuryTransactionPayload::KIND
+        );
+    }
+}
@@ -0,0 +1,196 @@
+// Copyright 2020 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_common::packable::Packable;
+use bee_message::{input::canonical_sort_inputs, output::canonical_sort, prelude::*};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+const SEED: u64 = 0x6265_652d_6d65_7373;
+const ITERATIONS: usize = 500;
+
+fn vec_bytes(rng: &mut StdRng, len: usize) -> Vec<u8> {
+    (0..len).map(|_| rng.gen()).collect()
+}
+
+fn address(rng: &mut StdRng) -> Address {
+    Ed25519Address::new(rng.gen()).into()
+}
+
+fn parents(rng: &mut StdRng) -> Parents {
+    let mut parents = (0..rng.gen_range(1..=8))
+        .map(|_| MessageId::new(rng.gen()))
+        .collect::<Vec<_>>();
+    parents.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+    parents.dedup();
+
+    Parents::new(parents).unwrap()
+}
+
+fn indexation(rng: &mut StdRng) -> IndexationPayload {
+    let index_len = rng.gen_range(1..=64);
+    let data_len = rng.gen_range(0..=256);
+
+    IndexationPayload::new(&vec_bytes(rng, index_len), &vec_bytes(rng, data_len)).unwrap()
+}
+
+fn transaction(rng: &mut StdRng) -> TransactionPayload {
+    let mut inputs = (0..rng.gen_range(1..=4))
+        .map(|_| {
+            UTXOInput::new(TransactionId::new(rng.gen()), rng.gen_range(0..127))
+                .unwrap()
+                .into()
+        })
+        .collect::<Vec<Input>>();
+    canonical_sort_inputs(&mut inputs);
+    inputs.dedup();
+
+    let mut outputs = (0..rng.gen_range(1..=4))
+        .map(|_| {
+            SignatureLockedSingleOutput::new(address(rng), rng.gen_range(1..=1_000_000_000))
+                .unwrap()
+                .into()
+        })
+        .collect::<Vec<Output>>();
+    canonical_sort(&mut outputs);
+
+    let mut builder = RegularEssence::builder()
+        .with_inputs(inputs.clone())
+        .with_outputs(outputs);
+    if rng.gen() {
+        builder = builder.with_payload(Payload::Indexation(Box::new(indexation(rng))));
+    }
+
+    let unlock_blocks = (0..inputs.len())
+        .map(|index| {
+            if index > 0 && rng.gen() {
+                UnlockBlock::Reference(ReferenceUnlock::new(0).unwrap())
+            } else {
+                UnlockBlock::Signature(SignatureUnlock::Ed25519(Ed25519Signature::new(
+                    rng.gen(),
+                    vec_bytes(rng, 64).into_boxed_slice(),
+                )))
+            }
+        })
+        .collect();
+
+    TransactionPayload::builder()
+        .with_essence(Essence::Regular(builder.finish().unwrap()))
+        .with_unlock_blocks(UnlockBlocks::new(unlock_blocks).unwrap())
+        .finish()
+        .unwrap()
+}
+
+fn treasury_transaction(rng: &mut StdRng) -> TreasuryTransactionPayload {
+    TreasuryTransactionPayload::new(
+        TreasuryInput::new(MessageId::new(rng.gen())).into(),
+        TreasuryOutput::new(rng.gen_range(0..=IOTA_SUPPLY)).unwrap().into(),
+    )
+    .unwrap()
+}
+
+fn receipt(rng: &mut StdRng) -> ReceiptPayload {
+    let mut funds = (0..rng.gen_range(1..=16))
+        .map(|_| {
+            let mut tail_transaction_hash = [0u8; 49];
+            rng.fill(&mut tail_transaction_hash[..]);
+            MigratedFundsEntry::new(
+                tail_transaction_hash,
+                SignatureLockedSingleOutput::new(address(rng), rng.gen_range(1_000_000..=1_000_000_000)).unwrap(),
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    funds.sort_by_cached_key(Packable::pack_new);
+
+    ReceiptPayload::new(
+        MilestoneIndex::new(rng.gen()),
+        rng.gen(),
+        funds,
+        Payload::TreasuryTransaction(Box::new(treasury_transaction(rng))),
+    )
+    .unwrap()
+}
+
+fn milestone(rng: &mut StdRng) -> MilestonePayload {
+    let mut public_keys = (0..rng.gen_range(1..=8))
+        .map(|_| rng.gen::<[u8; MILESTONE_PUBLIC_KEY_LENGTH]>())
+        .collect::<Vec<_>>();
+    public_keys.sort_unstable();
+    public_keys.dedup();
+    let signatures = public_keys
+        .iter()
+        .map(|_| vec_bytes(rng, MILESTONE_SIGNATURE_LENGTH).into_boxed_slice())
+        .collect();
+    let receipt = if rng.gen() {
+        Some(Payload::Receipt(Box::new(receipt(rng))))
+    } else {
+        None
+    };
+
+    MilestonePayload::new(
+        MilestonePayloadEssence::new(
+            MilestoneIndex::new(rng.gen()),
+            rng.gen(),
+            parents(rng),
+            rng.gen(),
+            public_keys,
+            receipt,
+        )
+        .unwrap(),
+        signatures,
+    )
+    .unwrap()
+}
+
+fn assert_round_trip(payload: Payload) {
+    let packed = payload.pack_new();
+
+    assert_eq!(payload.packed_len(), packed.len());
+    assert_eq!(Payload::unpack(&mut packed.as_slice()).unwrap(), payload);
+}
+
+#[test]
+fn indexation_round_trip() {
+    let mut rng = StdRng::seed_from_u64(SEED);
+
+    for _ in 0..ITERATIONS {
+        assert_round_trip(Payload::Indexation(Box::new(indexation(&mut rng))));
+    }
+}
+
+#[test]
+fn transaction_round_trip() {
+    let mut rng = StdRng::seed_from_u64(SEED);
+
+    for _ in 0..ITERATIONS {
+        assert_round_trip(Payload::Transaction(Box::new(transaction(&mut rng))));
+    }
+}
+
+#[test]
+fn treasury_transaction_round_trip() {
+    let mut rng = StdRng::seed_from_u64(SEED);
+
+    for _ in 0..ITERATIONS {
+        assert_round_trip(Payload::TreasuryTransaction(Box::new(treasury_transaction(&mut rng))));
+    }
+}
+
+#[test]
+fn receipt_round_trip() {
+    let mut rng = StdRng::seed_from_u64(SEED);
+
+    for _ in 0..ITERATIONS {
+        assert_round_trip(Payload::Receipt(Box::new(receipt(&mut rng))));
+    }
+}
+
+#[test]
+fn milestone_round_trip() {
+    let mut rng = StdRng::seed_from_u64(SEED);
+
+    for _ in 0..ITERATIONS {
+        assert_round_trip(Payload::Milestone(Box::new(milestone(&mut rng))));
+    }
+}
@@ -7,3 +7,33 @@ use bee_message::prelude::*;
 fn kind() {
     assert_eq!(ReceiptPayload::KIND, 3);
 }
+
+#[test]
+fn new_funds_not_sorted() {
+    let mut funds = (1..=3)
+        .map(|i| {
+            MigratedFundsEntry::new(
+                [i; 49],
+                SignatureLockedSingleOutput::new(Address::from(Ed25519Address::new([i; 32])), 1_000_000).unwrap(),
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    funds.swap(1, 2);
+
+    assert!(matches!(
+        ReceiptPayload::new(
+            MilestoneIndex::new(0),
+            false,
+            funds,
+            Payload::TreasuryTransaction(Box::new(
+                TreasuryTransactionPayload::new(
+                    TreasuryInput::new(MessageId::new([0; 32])).into(),
+                    TreasuryOutput::new(1_000_000).unwrap().into(),
+                )
+                .unwrap()
+            )),
+        ),
+        Err(Error::MigratedFundsNotSorted(2))
+    ));
+}
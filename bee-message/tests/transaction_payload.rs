@@ -1,9 +1,253 @@
 // Copyright 2020 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use bee_message::prelude::*;
+use bee_common::packable::Packable;
+use bee_message::{
+    input::canonical_sort_inputs, output::canonical_sort, payload::transaction::validate_block, prelude::*,
+};
+use bee_test::rand::{address::rand_address, input::rand_utxo_input};
+
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    signatures::ed25519::SecretKey,
+};
 
 #[test]
 fn kind() {
     assert_eq!(TransactionPayload::KIND, 0);
 }
+
+#[test]
+fn with_signatures_same_address() {
+    let secret_key = SecretKey::generate().unwrap();
+    let address = Address::from(Ed25519Address::new(
+        Blake2b256::digest(&secret_key.public_key().to_compressed_bytes()).into(),
+    ));
+    let mut inputs: Vec<Input> = vec![rand_utxo_input().into(), rand_utxo_input().into()];
+    canonical_sort_inputs(&mut inputs);
+    let essence = Essence::from(
+        RegularEssence::builder()
+            .with_inputs(inputs)
+            .add_output(SignatureLockedSingleOutput::new(rand_address(), 1_000).unwrap().into())
+            .finish()
+            .unwrap(),
+    );
+
+    let transaction = TransactionPayload::builder()
+        .with_signatures(
+            essence,
+            &[
+                (address, SecretKey::from_le_bytes(secret_key.to_le_bytes()).unwrap()),
+                (address, secret_key),
+            ],
+        )
+        .unwrap()
+        .finish()
+        .unwrap();
+    let unlock_blocks = transaction.unlock_blocks();
+
+    assert_eq!(unlock_blocks.len(), 2);
+    match &unlock_blocks[0] {
+        UnlockBlock::Signature(signature) => assert!(address.verify(&transaction.essence().hash(), signature).is_ok()),
+        _ => panic!("expected a signature unlock block"),
+    }
+    assert!(matches!(&unlock_blocks[1], UnlockBlock::Reference(reference) if reference.index() == 0));
+}
+
+#[test]
+fn with_signatures_address_mismatch() {
+    let essence = Essence::from(
+        RegularEssence::builder()
+            .add_input(rand_utxo_input().into())
+            .add_output(SignatureLockedSingleOutput::new(rand_address(), 1_000).unwrap().into())
+            .finish()
+            .unwrap(),
+    );
+
+    assert!(matches!(
+        TransactionPayload::builder().with_signatures(essence, &[(rand_address(), SecretKey::generate().unwrap())]),
+        Err(Error::SignaturePublicKeyMismatch(_, _))
+    ));
+}
+
+#[test]
+fn created_outputs() {
+    let secret_key = SecretKey::generate().unwrap();
+    let address = Address::from(Ed25519Address::new(
+        Blake2b256::digest(&secret_key.public_key().to_compressed_bytes()).into(),
+    ));
+    let mut outputs: Vec<Output> = vec![
+        SignatureLockedSingleOutput::new(rand_address(), 1_000).unwrap().into(),
+        SignatureLockedSingleOutput::new(rand_address(), 2_000).unwrap().into(),
+    ];
+    canonical_sort(&mut outputs);
+    let essence = Essence::from(
+        RegularEssence::builder()
+            .add_input(rand_utxo_input().into())
+            .with_outputs(outputs.clone())
+            .finish()
+            .unwrap(),
+    );
+    let transaction = TransactionPayload::builder()
+        .with_signatures(essence, &[(address, secret_key)])
+        .unwrap()
+        .finish()
+        .unwrap();
+
+    let created_outputs = transaction.created_outputs();
+
+    assert_eq!(created_outputs.len(), 2);
+    for (index, (output_id, output)) in created_outputs.into_iter().enumerate() {
+        assert_eq!(*output_id.transaction_id(), transaction.id());
+        assert_eq!(output_id.index(), index as u16);
+        assert_eq!(*output, outputs[index]);
+    }
+}
+
+#[test]
+fn essence_as_packed() {
+    let essence = Essence::from(
+        RegularEssence::builder()
+            .add_input(rand_utxo_input().into())
+            .add_output(SignatureLockedSingleOutput::new(rand_address(), 1_000).unwrap().into())
+            .finish()
+            .unwrap(),
+    );
+    let packed = essence.as_packed();
+
+    assert_eq!(packed.len(), essence.packed_len());
+    assert_eq!(essence.hash(), <[u8; 32]>::from(Blake2b256::digest(&packed)));
+}
+
+fn transaction_consuming(input: UTXOInput) -> TransactionPayload {
+    TransactionPayload::builder()
+        .with_essence(
+            RegularEssence::builder()
+                .add_input(input.into())
+                .add_output(SignatureLockedSingleOutput::new(rand_address(), 1_000).unwrap().into())
+                .finish()
+                .unwrap()
+                .into(),
+        )
+        .with_unlock_blocks(
+            UnlockBlocks::new(vec![UnlockBlock::Signature(SignatureUnlock::Ed25519(
+                Ed25519Signature::new([0; 32], Box::new([0; 64])),
+            ))])
+            .unwrap(),
+        )
+        .finish()
+        .unwrap()
+}
+
+#[test]
+fn validate_block_valid() {
+    let transaction_1 = transaction_consuming(rand_utxo_input());
+    let transaction_2 = transaction_consuming(rand_utxo_input());
+
+    assert!(validate_block(&[&transaction_1, &transaction_2]).is_ok());
+}
+
+#[test]
+fn validate_block_double_spend() {
+    let input = rand_utxo_input();
+    let transaction_1 = transaction_consuming(rand_utxo_input());
+    let transaction_2 = transaction_consuming(input.clone());
+    let transaction_3 = transaction_consuming(input.clone());
+
+    assert!(matches!(
+        validate_block(&[&transaction_1, &transaction_2, &transaction_3]),
+        Err(Error::ConflictingInputs(output_id, 1, 2)) if output_id == *input.output_id()
+    ));
+}
+
+fn signed_transaction() -> (TransactionPayload, Vec<Address>) {
+    let secret_keys = vec![SecretKey::generate().unwrap(), SecretKey::generate().unwrap()];
+    let addresses = secret_keys
+        .iter()
+        .map(|secret_key| {
+            Address::from(Ed25519Address::new(
+                Blake2b256::digest(&secret_key.public_key().to_compressed_bytes()).into(),
+            ))
+        })
+        .collect::<Vec<_>>();
+    let mut inputs: Vec<Input> = vec![
+        rand_utxo_input().into(),
+        rand_utxo_input().into(),
+        rand_utxo_input().into(),
+    ];
+    canonical_sort_inputs(&mut inputs);
+    let essence = Essence::from(
+        RegularEssence::builder()
+            .with_inputs(inputs)
+            .add_output(SignatureLockedSingleOutput::new(rand_address(), 1_000).unwrap().into())
+            .finish()
+            .unwrap(),
+    );
+    let input_addresses = vec![addresses[0], addresses[1], addresses[0]];
+    let keyed_inputs = input_addresses
+        .iter()
+        .zip([0, 1, 0].iter())
+        .map(|(address, index)| {
+            (
+                *address,
+                SecretKey::from_le_bytes(secret_keys[*index].to_le_bytes()).unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let transaction = TransactionPayload::builder()
+        .with_signatures(essence, &keyed_inputs)
+        .unwrap()
+        .finish()
+        .unwrap();
+
+    (transaction, input_addresses)
+}
+
+#[test]
+fn verify_signatures_valid() {
+    let (transaction, input_addresses) = signed_transaction();
+
+    assert!(transaction.verify_signatures(&input_addresses).is_ok());
+}
+
+#[test]
+fn verify_signatures_address_mismatch() {
+    let (transaction, mut input_addresses) = signed_transaction();
+    input_addresses.swap(0, 1);
+
+    assert!(matches!(
+        transaction.verify_signatures(&input_addresses),
+        Err(Error::SignaturePublicKeyMismatch(_, _))
+    ));
+}
+
+#[test]
+fn verify_signatures_tampered() {
+    let (transaction, input_addresses) = signed_transaction();
+    let unlock_blocks = transaction
+        .unlock_blocks()
+        .iter()
+        .map(|unlock_block| match unlock_block {
+            UnlockBlock::Signature(SignatureUnlock::Ed25519(signature)) => {
+                let mut tampered = signature.signature().to_vec();
+                tampered[0] ^= 0xff;
+                UnlockBlock::Signature(SignatureUnlock::Ed25519(Ed25519Signature::new(
+                    *signature.public_key(),
+                    tampered.into_boxed_slice(),
+                )))
+            }
+            unlock_block => unlock_block.clone(),
+        })
+        .collect();
+    let transaction = TransactionPayload::builder()
+        .with_essence(transaction.essence().clone())
+        .with_unlock_blocks(UnlockBlocks::new(unlock_blocks).unwrap())
+        .finish()
+        .unwrap();
+
+    assert!(matches!(
+        transaction.verify_signatures(&input_addresses),
+        Err(Error::InvalidSignature)
+    ));
+}
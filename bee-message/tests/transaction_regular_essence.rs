@@ -1,9 +1,311 @@
 // Copyright 2020 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use bee_message::prelude::*;
+use bee_common::packable::Packable;
+use bee_message::{input::canonical_sort_inputs, output::canonical_sort, prelude::*};
+use bee_test::packable::CountingWriter;
+
+use core::str::FromStr;
+
+const OUTPUT_ID_1: &str = "52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c6492a00";
+const OUTPUT_ID_2: &str = "52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c6492b00";
+const OUTPUT_ID_3: &str = "52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c6492900";
+const ED25519_ADDRESS_1: &str = "52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649";
+const ED25519_ADDRESS_2: &str = "ff2d3e4fd8bc5ef3d1b1c06ee3b1a0e4c2c8f60c2b9ffd2e3e8e9f1f0b7ad3c1";
 
 #[test]
 fn kind() {
     assert_eq!(RegularEssence::KIND, 0);
 }
+
+#[test]
+fn with_sorted_valid() {
+    let inputs: Vec<Input> = vec![
+        UTXOInput::from_str(OUTPUT_ID_2).unwrap().into(),
+        UTXOInput::from_str(OUTPUT_ID_3).unwrap().into(),
+        UTXOInput::from_str(OUTPUT_ID_1).unwrap().into(),
+    ];
+    let outputs: Vec<Output> = vec![
+        SignatureLockedSingleOutput::new(
+            Address::from(Ed25519Address::from_str(ED25519_ADDRESS_2).unwrap()),
+            1_000,
+        )
+        .unwrap()
+        .into(),
+        SignatureLockedSingleOutput::new(
+            Address::from(Ed25519Address::from_str(ED25519_ADDRESS_1).unwrap()),
+            1_000,
+        )
+        .unwrap()
+        .into(),
+    ];
+    let essence = RegularEssence::builder()
+        .with_inputs(inputs.clone())
+        .with_outputs(outputs.clone())
+        .with_sorted()
+        .finish()
+        .unwrap();
+
+    assert_eq!(
+        essence.inputs(),
+        &[inputs[1].clone(), inputs[2].clone(), inputs[0].clone()]
+    );
+    assert_eq!(essence.outputs(), &[outputs[1].clone(), outputs[0].clone()]);
+}
+
+#[test]
+fn canonical_sort_valid() {
+    let mut inputs: Vec<Input> = vec![
+        UTXOInput::from_str(OUTPUT_ID_2).unwrap().into(),
+        UTXOInput::from_str(OUTPUT_ID_1).unwrap().into(),
+    ];
+    let mut outputs: Vec<Output> = vec![
+        SignatureLockedSingleOutput::new(
+            Address::from(Ed25519Address::from_str(ED25519_ADDRESS_2).unwrap()),
+            1_000,
+        )
+        .unwrap()
+        .into(),
+        SignatureLockedSingleOutput::new(
+            Address::from(Ed25519Address::from_str(ED25519_ADDRESS_1).unwrap()),
+            1_000,
+        )
+        .unwrap()
+        .into(),
+    ];
+
+    assert!(matches!(
+        RegularEssence::builder()
+            .with_inputs(inputs.clone())
+            .with_outputs(outputs.clone())
+            .finish(),
+        Err(Error::TransactionInputsNotSorted(1))
+    ));
+
+    canonical_sort_inputs(&mut inputs);
+
+    assert!(matches!(
+        RegularEssence::builder()
+            .with_inputs(inputs.clone())
+            .with_outputs(outputs.clone())
+            .finish(),
+        Err(Error::TransactionOutputsNotSorted(1))
+    ));
+
+    canonical_sort(&mut outputs);
+
+    assert!(RegularEssence::builder()
+        .with_inputs(inputs)
+        .with_outputs(outputs)
+        .finish()
+        .is_ok());
+}
+
+#[test]
+fn same_transaction_different_indices_valid() {
+    let output: Output = SignatureLockedSingleOutput::new(
+        Address::from(Ed25519Address::from_str(ED25519_ADDRESS_1).unwrap()),
+        1_000,
+    )
+    .unwrap()
+    .into();
+
+    assert!(RegularEssence::builder()
+        .add_input(UTXOInput::from_str(OUTPUT_ID_1).unwrap().into())
+        .add_input(UTXOInput::from_str(OUTPUT_ID_2).unwrap().into())
+        .add_output(output)
+        .finish()
+        .is_ok());
+}
+
+#[test]
+fn duplicate_inputs_invalid() {
+    let output: Output = SignatureLockedSingleOutput::new(
+        Address::from(Ed25519Address::from_str(ED25519_ADDRESS_1).unwrap()),
+        1_000,
+    )
+    .unwrap()
+    .into();
+
+    assert!(matches!(
+        RegularEssence::builder()
+            .add_input(UTXOInput::from_str(OUTPUT_ID_1).unwrap().into())
+            .add_input(UTXOInput::from_str(OUTPUT_ID_1).unwrap().into())
+            .add_output(output)
+            .finish(),
+        Err(Error::DuplicateError)
+    ));
+}
+
+#[test]
+fn packed_len_matches_pack() {
+    let output: Output = SignatureLockedSingleOutput::new(
+        Address::from(Ed25519Address::from_str(ED25519_ADDRESS_1).unwrap()),
+        1_000,
+    )
+    .unwrap()
+    .into();
+    let essence = Essence::from(
+        RegularEssence::builder()
+            .add_input(UTXOInput::from_str(OUTPUT_ID_1).unwrap().into())
+            .add_input(UTXOInput::from_str(OUTPUT_ID_2).unwrap().into())
+            .add_output(output)
+            .with_payload(IndexationPayload::new(&[42], &[0x42, 0xff, 0x84, 0xa2]).unwrap().into())
+            .finish()
+            .unwrap(),
+    );
+    let mut writer = CountingWriter::new();
+
+    essence.pack(&mut writer).unwrap();
+
+    assert_eq!(writer.count(), essence.packed_len());
+}
+
+#[test]
+fn unsorted_inputs_index() {
+    let output: Output = SignatureLockedSingleOutput::new(
+        Address::from(Ed25519Address::from_str(ED25519_ADDRESS_1).unwrap()),
+        1_000,
+    )
+    .unwrap()
+    .into();
+
+    assert!(matches!(
+        RegularEssence::builder()
+            .add_input(UTXOInput::from_str(OUTPUT_ID_1).unwrap().into())
+            .add_input(UTXOInput::from_str(OUTPUT_ID_2).unwrap().into())
+            .add_input(UTXOInput::from_str(OUTPUT_ID_3).unwrap().into())
+            .add_output(output)
+            .finish(),
+        Err(Error::TransactionInputsNotSorted(2))
+    ));
+}
+
+#[test]
+fn unsorted_outputs_index() {
+    let output_1: Output = SignatureLockedSingleOutput::new(
+        Address::from(Ed25519Address::from_str(ED25519_ADDRESS_1).unwrap()),
+        1_000,
+    )
+    .unwrap()
+    .into();
+    let output_2: Output = SignatureLockedSingleOutput::new(
+        Address::from(Ed25519Address::from_str(ED25519_ADDRESS_2).unwrap()),
+        1_000,
+    )
+    .unwrap()
+    .into();
+
+    assert!(matches!(
+        RegularEssence::builder()
+            .add_input(UTXOInput::from_str(OUTPUT_ID_1).unwrap().into())
+            .add_output(output_2)
+            .add_output(output_1)
+            .finish(),
+        Err(Error::TransactionOutputsNotSorted(1))
+    ));
+}
+
+#[test]
+fn consumed_output_ids() {
+    let output: Output = SignatureLockedSingleOutput::new(
+        Address::from(Ed25519Address::from_str(ED25519_ADDRESS_1).unwrap()),
+        1_000,
+    )
+    .unwrap()
+    .into();
+    let essence = Essence::from(
+        RegularEssence::builder()
+            .add_input(UTXOInput::from_str(OUTPUT_ID_1).unwrap().into())
+            .add_input(UTXOInput::from_str(OUTPUT_ID_2).unwrap().into())
+            .add_output(output)
+            .finish()
+            .unwrap(),
+    );
+
+    assert_eq!(
+        essence.consumed_output_ids(),
+        vec![
+            OutputId::from_str(OUTPUT_ID_1).unwrap(),
+            OutputId::from_str(OUTPUT_ID_2).unwrap()
+        ]
+    );
+}
+
+#[test]
+fn unpack_unvalidated_valid() {
+    let output: Output = SignatureLockedSingleOutput::new(
+        Address::from(Ed25519Address::from_str(ED25519_ADDRESS_1).unwrap()),
+        1_000,
+    )
+    .unwrap()
+    .into();
+    let essence = Essence::from(
+        RegularEssence::builder()
+            .add_input(UTXOInput::from_str(OUTPUT_ID_1).unwrap().into())
+            .add_input(UTXOInput::from_str(OUTPUT_ID_2).unwrap().into())
+            .add_output(output)
+            .finish()
+            .unwrap(),
+    );
+    let bytes = essence.pack_new();
+
+    assert_eq!(Essence::unpack_unvalidated(&mut bytes.as_slice()).unwrap(), essence);
+    assert_eq!(Essence::unpack(&mut bytes.as_slice()).unwrap(), essence);
+}
+
+#[test]
+fn unpack_unvalidated_unsorted_inputs() {
+    let output: Output = SignatureLockedSingleOutput::new(
+        Address::from(Ed25519Address::from_str(ED25519_ADDRESS_1).unwrap()),
+        1_000,
+    )
+    .unwrap()
+    .into();
+    let mut bytes = vec![RegularEssence::KIND];
+    2u16.pack(&mut bytes).unwrap();
+    Input::from(UTXOInput::from_str(OUTPUT_ID_2).unwrap())
+        .pack(&mut bytes)
+        .unwrap();
+    Input::from(UTXOInput::from_str(OUTPUT_ID_1).unwrap())
+        .pack(&mut bytes)
+        .unwrap();
+    1u16.pack(&mut bytes).unwrap();
+    output.pack(&mut bytes).unwrap();
+    0u32.pack(&mut bytes).unwrap();
+
+    assert!(matches!(
+        Essence::unpack(&mut bytes.as_slice()),
+        Err(Error::TransactionInputsNotSorted(1))
+    ));
+
+    let essence = Essence::unpack_unvalidated(&mut bytes.as_slice()).unwrap();
+
+    assert_eq!(
+        essence.consumed_output_ids(),
+        vec![
+            OutputId::from_str(OUTPUT_ID_2).unwrap(),
+            OutputId::from_str(OUTPUT_ID_1).unwrap()
+        ]
+    );
+}
+
+#[test]
+fn output_addresses() {
+    let address_1 = Address::from(Ed25519Address::from_str(ED25519_ADDRESS_1).unwrap());
+    let address_2 = Address::from(Ed25519Address::from_str(ED25519_ADDRESS_2).unwrap());
+    let essence = Essence::from(
+        RegularEssence::builder()
+            .add_input(UTXOInput::from_str(OUTPUT_ID_1).unwrap().into())
+            .add_output(SignatureLockedSingleOutput::new(address_2, 1_000).unwrap().into())
+            .add_output(
+                SignatureLockedDustAllowanceOutput::new(address_1, 1_000_000)
+                    .unwrap()
+                    .into(),
+            )
+            .finish()
+            .unwrap(),
+    );
+
+    assert_eq!(essence.output_addresses(), vec![&address_2, &address_1]);
+}
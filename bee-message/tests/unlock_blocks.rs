@@ -1,6 +1,7 @@
 // Copyright 2020 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use bee_common::packable::Packable;
 use bee_message::prelude::*;
 
 #[test]
@@ -83,3 +84,47 @@ fn new_valid() {
     ])
     .is_ok());
 }
+
+#[test]
+fn unpack_invalid_count_less_than_min() {
+    assert!(matches!(
+        UnlockBlocks::unpack(&mut vec![0, 0].as_slice()),
+        Err(Error::InvalidUnlockBlockCount(0)),
+    ));
+}
+
+#[test]
+fn unpack_invalid_count_more_than_max() {
+    assert!(matches!(
+        UnlockBlocks::unpack(&mut vec![128, 0].as_slice()),
+        Err(Error::InvalidUnlockBlockCount(128)),
+    ));
+}
+
+#[test]
+fn unpack_invalid_reference() {
+    let mut bytes = 2u16.pack_new();
+    UnlockBlock::from(ReferenceUnlock::new(1).unwrap())
+        .pack(&mut bytes)
+        .unwrap();
+    UnlockBlock::from(SignatureUnlock::from(Ed25519Signature::new([0; 32], Box::new([0; 64]))))
+        .pack(&mut bytes)
+        .unwrap();
+
+    assert!(matches!(
+        UnlockBlocks::unpack(&mut bytes.as_slice()),
+        Err(Error::InvalidUnlockBlockReference(0)),
+    ));
+}
+
+#[test]
+fn pack_unpack_valid() {
+    let unlock_blocks_1 = UnlockBlocks::new(vec![
+        SignatureUnlock::from(Ed25519Signature::new([0; 32], Box::new([0; 64]))).into(),
+        ReferenceUnlock::new(0).unwrap().into(),
+    ])
+    .unwrap();
+    let unlock_blocks_2 = UnlockBlocks::unpack(&mut unlock_blocks_1.pack_new().as_slice()).unwrap();
+
+    assert_eq!(unlock_blocks_1, unlock_blocks_2);
+}
@@ -0,0 +1,101 @@
+// Copyright 2020 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::{address::Ed25519Address, output::OutputId};
+use bee_storage::{
+    access::{AsStream, Batch, BatchBuilder, Delete, Exist, Fetch, Insert, Truncate},
+    backend::StorageBackend,
+};
+use bee_storage_rocksdb::{config::RocksDBConfigBuilder, storage::Storage};
+use bee_test::rand::{address::rand_ed25519_address, output::rand_output_id};
+
+use futures::stream::StreamExt;
+
+const DB_DIRECTORY: &str = "./tests/database/ed25519_address_to_output_id";
+
+#[tokio::test]
+async fn access() {
+    let _ = std::fs::remove_dir_all(DB_DIRECTORY);
+
+    let config = RocksDBConfigBuilder::default().with_path(DB_DIRECTORY.into()).finish();
+    let storage = Storage::start(config).await.unwrap();
+
+    let (address, output_id) = (rand_ed25519_address(), rand_output_id());
+
+    assert!(
+        !Exist::<(Ed25519Address, OutputId), ()>::exist(&storage, &(address, output_id))
+            .await
+            .unwrap()
+    );
+    assert!(Fetch::<Ed25519Address, Vec<OutputId>>::fetch(&storage, &address)
+        .await
+        .unwrap()
+        .unwrap()
+        .is_empty());
+
+    Insert::<(Ed25519Address, OutputId), ()>::insert(&storage, &(address, output_id), &())
+        .await
+        .unwrap();
+
+    assert!(
+        Exist::<(Ed25519Address, OutputId), ()>::exist(&storage, &(address, output_id))
+            .await
+            .unwrap()
+    );
+    assert_eq!(
+        Fetch::<Ed25519Address, Vec<OutputId>>::fetch(&storage, &address)
+            .await
+            .unwrap()
+            .unwrap(),
+        vec![output_id]
+    );
+
+    Delete::<(Ed25519Address, OutputId), ()>::delete(&storage, &(address, output_id))
+        .await
+        .unwrap();
+
+    assert!(
+        !Exist::<(Ed25519Address, OutputId), ()>::exist(&storage, &(address, output_id))
+            .await
+            .unwrap()
+    );
+
+    let address = rand_ed25519_address();
+    let mut kept = Vec::new();
+    let mut batch = Storage::batch_begin();
+
+    for i in 0usize..10usize {
+        let output_id = rand_output_id();
+        Insert::<(Ed25519Address, OutputId), ()>::insert(&storage, &(address, output_id), &())
+            .await
+            .unwrap();
+        if i % 2 == 0 {
+            Batch::<(Ed25519Address, OutputId), ()>::batch_delete(&storage, &mut batch, &(address, output_id)).unwrap();
+        } else {
+            kept.push(output_id);
+        }
+    }
+
+    storage.batch_commit(batch, true).await.unwrap();
+
+    let mut fetched = Fetch::<Ed25519Address, Vec<OutputId>>::fetch(&storage, &address)
+        .await
+        .unwrap()
+        .unwrap();
+    fetched.sort();
+    kept.sort();
+
+    assert_eq!(fetched, kept);
+
+    Truncate::<(Ed25519Address, OutputId), ()>::truncate(&storage)
+        .await
+        .unwrap();
+
+    let mut stream = AsStream::<(Ed25519Address, OutputId), ()>::stream(&storage)
+        .await
+        .unwrap();
+
+    assert!(stream.next().await.is_none());
+
+    let _ = std::fs::remove_dir_all(DB_DIRECTORY);
+}
@@ -0,0 +1,71 @@
+// Copyright 2020 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_ledger::types::Unspent;
+use bee_storage::{
+    access::{AsStream, Batch, BatchBuilder, Delete, Exist, Insert, Truncate},
+    backend::StorageBackend,
+};
+use bee_storage_rocksdb::{config::RocksDBConfigBuilder, storage::Storage};
+use bee_test::rand::output::rand_output_id;
+
+use futures::stream::StreamExt;
+
+use std::collections::HashSet;
+
+const DB_DIRECTORY: &str = "./tests/database/output_id_unspent";
+
+#[tokio::test]
+async fn access() {
+    let _ = std::fs::remove_dir_all(DB_DIRECTORY);
+
+    let config = RocksDBConfigBuilder::default().with_path(DB_DIRECTORY.into()).finish();
+    let storage = Storage::start(config).await.unwrap();
+
+    let unspent = Unspent::new(rand_output_id());
+
+    assert!(!Exist::<Unspent, ()>::exist(&storage, &unspent).await.unwrap());
+
+    Insert::<Unspent, ()>::insert(&storage, &unspent, &()).await.unwrap();
+
+    assert!(Exist::<Unspent, ()>::exist(&storage, &unspent).await.unwrap());
+
+    Delete::<Unspent, ()>::delete(&storage, &unspent).await.unwrap();
+
+    assert!(!Exist::<Unspent, ()>::exist(&storage, &unspent).await.unwrap());
+
+    let mut kept = HashSet::new();
+    let mut batch = Storage::batch_begin();
+
+    for i in 0usize..10usize {
+        let output_id = rand_output_id();
+        Insert::<Unspent, ()>::insert(&storage, &Unspent::new(output_id), &())
+            .await
+            .unwrap();
+        if i % 2 == 0 {
+            Batch::<Unspent, ()>::batch_delete(&storage, &mut batch, &Unspent::new(output_id)).unwrap();
+        } else {
+            kept.insert(output_id);
+        }
+    }
+
+    storage.batch_commit(batch, true).await.unwrap();
+
+    let mut stream = AsStream::<Unspent, ()>::stream(&storage).await.unwrap();
+    let mut count = 0;
+
+    while let Some((unspent, ())) = stream.next().await {
+        assert!(kept.contains(unspent.id()));
+        count += 1;
+    }
+
+    assert_eq!(count, kept.len());
+
+    Truncate::<Unspent, ()>::truncate(&storage).await.unwrap();
+
+    let mut stream = AsStream::<Unspent, ()>::stream(&storage).await.unwrap();
+
+    assert!(stream.next().await.is_none());
+
+    let _ = std::fs::remove_dir_all(DB_DIRECTORY);
+}
@@ -0,0 +1,31 @@
+// Copyright 2020 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::io::{Result, Write};
+
+/// A writer that discards everything written to it and only counts the bytes.
+/// Packing a value into it allows cross-checking `Packable::packed_len` against `Packable::pack`.
+#[derive(Default)]
+pub struct CountingWriter(usize);
+
+impl CountingWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn count(&self) -> usize {
+        self.0
+    }
+}
+
+impl Write for CountingWriter {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.0 += buf.len();
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -1,10 +1,24 @@
 // Copyright 2020 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::rand::{integer::rand_integer_range, transaction::rand_transaction_id};
+use crate::rand::{
+    address::rand_address, integer::rand_integer_range, message::rand_message_id, transaction::rand_transaction_id,
+};
 
-use bee_message::{constants::INPUT_OUTPUT_INDEX_RANGE, output::OutputId};
+use bee_message::{
+    constants::{INPUT_OUTPUT_INDEX_RANGE, IOTA_SUPPLY},
+    output::{CreatedOutput, OutputId, SignatureLockedSingleOutput},
+};
 
 pub fn rand_output_id() -> OutputId {
     OutputId::new(rand_transaction_id(), rand_integer_range(INPUT_OUTPUT_INDEX_RANGE)).unwrap()
 }
+
+pub fn rand_created_output() -> CreatedOutput {
+    CreatedOutput::new(
+        rand_message_id(),
+        SignatureLockedSingleOutput::new(rand_address(), rand_integer_range(1..=IOTA_SUPPLY))
+            .unwrap()
+            .into(),
+    )
+}